@@ -0,0 +1,30 @@
+//! Support for reading from byte deques through [`bytes`].
+//!
+//! [`bytes`]: https://docs.rs/bytes
+
+use ::bytes::Buf;
+
+use crate::FixedVecDeque;
+
+impl<const N: usize> Buf for FixedVecDeque<[u8; N]> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.as_slices().0
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(
+            cnt <= self.len,
+            "cannot advance past `remaining`: {} <= {}",
+            cnt,
+            self.len
+        );
+
+        self.len -= cnt;
+    }
+}
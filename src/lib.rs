@@ -119,19 +119,36 @@
 
 #![cfg_attr(nightly, feature(test))]
 
+#[cfg(feature = "bytes")]
+mod bytes;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "proptest")]
+pub use self::proptest::fixed_vec_deque_strategy;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
 /// Code extensively based on Rust stdlib:
 /// https://github.com/rust-lang/rust/blob/e8aef7cae14bc7a56859408c90253e9bcc07fcff/src/liballoc/collections/vec_deque.rs
 /// And rust-smallvec:
 /// https://github.com/servo/rust-smallvec
 use std::cmp;
+use std::collections::VecDeque;
+use std::error;
 use std::fmt;
 use std::hash;
-use std::iter::{repeat, FromIterator};
+use std::iter::{self, repeat, FromIterator};
 use std::marker;
 use std::mem;
-use std::ops::{Index, IndexMut};
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
 use std::ptr;
 use std::slice;
+use std::vec;
 
 /// A double-ended queue implemented with a fixed buffer.
 pub struct FixedVecDeque<T>
@@ -142,29 +159,61 @@ where
     head: usize,
     // how many valid elements we have in the queue.
     len: usize,
+    // how many elements have been overwritten since the last clear.
+    #[cfg(feature = "overwrite-count")]
+    overwrites: u64,
     // underlying array.
     data: T,
 }
 
+/// A `FixedVecDeque` backed by an array of `N` elements of type `T`.
+///
+/// This avoids having to spell out the array type when naming a `FixedVecDeque`.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_vec_deque::RingBuffer;
+///
+/// let mut buf = RingBuffer::<u32, 4>::new();
+/// buf.extend([1, 2, 3, 4, 5]);
+///
+/// assert_eq!(buf.capacity(), 4);
+/// assert_eq!(buf, [2, 3, 4, 5]);
+/// ```
+pub type RingBuffer<T, const N: usize> = FixedVecDeque<[T; N]>;
+
 impl<T> Clone for FixedVecDeque<T>
 where
     T: Array,
     T::Item: Clone,
 {
     fn clone(&self) -> Self {
-        let data = unsafe {
-            let mut data: mem::MaybeUninit<T> = mem::MaybeUninit::uninit();
-            slice::from_raw_parts_mut((*data.as_mut_ptr()).ptr_mut(), T::size())
-                .clone_from_slice(self.buffer_as_slice());
-            data.assume_init()
-        };
+        let data = T::from_fn(|i| unsafe { self.buffer(i) }.clone());
 
         FixedVecDeque {
             head: self.head,
             len: self.len,
+            #[cfg(feature = "overwrite-count")]
+            overwrites: self.overwrites,
             data,
         }
     }
+
+    fn clone_from(&mut self, source: &Self) {
+        unsafe {
+            self.buffer_as_mut_slice()
+                .clone_from_slice(source.buffer_as_slice());
+        }
+
+        self.head = source.head;
+        self.len = source.len;
+
+        #[cfg(feature = "overwrite-count")]
+        {
+            self.overwrites = source.overwrites;
+        }
+    }
 }
 
 impl<T> FixedVecDeque<T>
@@ -188,411 +237,642 @@ where
         FixedVecDeque {
             head: 0,
             len: 0,
+            #[cfg(feature = "overwrite-count")]
+            overwrites: 0,
             data: Self::data_from_default(),
         }
     }
 
-    /// Initialize stored data using `Default::default()`
-    fn data_from_default() -> T {
-        unsafe {
-            let mut data: mem::MaybeUninit<T> = mem::MaybeUninit::uninit();
-            let m = (*data.as_mut_ptr()).ptr_mut();
-
-            for o in 0..T::size() {
-                ptr::write(m.add(o), T::Item::default());
-            }
+    /// Construct a new fixed ring buffer with `len` elements initialized through [`Default`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than the capacity of the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut deq = FixedVecDeque::<[u32; 4]>::with_len(3);
+    /// assert_eq!(deq, [0, 0, 0]);
+    /// deq[1] = 2;
+    /// assert_eq!(deq, [0, 2, 0]);
+    /// ```
+    pub fn with_len(len: usize) -> Self {
+        assert!(len <= T::size(), "length beyond capacity");
 
-            data.assume_init()
+        FixedVecDeque {
+            head: if len == T::size() { 0 } else { len },
+            len,
+            #[cfg(feature = "overwrite-count")]
+            overwrites: 0,
+            data: Self::data_from_default(),
         }
     }
-}
 
-impl<T> FixedVecDeque<T>
-where
-    T: Array,
-{
-    /// Returns `true` if the `FixedVecDeque` is empty.
+    /// Creates a draining iterator that removes all elements from the `FixedVecDeque` and yields
+    /// them by value, front to back.
+    ///
+    /// Every drained slot is replaced with `Default::default()`. When the iterator is dropped, any
+    /// remaining elements are dropped as well and the `FixedVecDeque` is left empty.
     ///
     /// # Examples
     ///
     /// ```
-    /// # extern crate fixed_vec_deque;
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut v = FixedVecDeque::<[u32; 1]>::new();
-    /// assert!(v.is_empty());
-    /// *v.push_front() = 1;
-    /// assert!(!v.is_empty());
+    /// let mut buf = FixedVecDeque::<[String; 4]>::new();
+    /// buf.push_back().push_str("a");
+    /// buf.push_back().push_str("b");
+    ///
+    /// let drained = buf.drain().collect::<Vec<_>>();
+    /// assert_eq!(drained, ["a", "b"]);
+    /// assert!(buf.is_empty());
+    /// assert_eq!(buf.push_back(), "");
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { deq: self }
     }
 
-    /// Returns `true` if the `FixedVecDeque` is full.
+    /// Creates a draining iterator that removes elements from the front of the `FixedVecDeque`
+    /// and yields them by value, for as long as they satisfy the predicate.
     ///
-    /// Writing to a queue that is full will overwrite existing elements.
+    /// Draining stops at the first element for which `pred` returns `false`, which is left in
+    /// the `FixedVecDeque`. Every drained slot is replaced with `Default::default()`. If the
+    /// iterator is dropped before it's exhausted, no further elements are removed.
     ///
     /// # Examples
     ///
+    /// Expiring every entry older than a given timestamp:
+    ///
     /// ```
-    /// # extern crate fixed_vec_deque;
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut v = FixedVecDeque::<[u32; 1]>::new();
-    /// assert!(!v.is_full());
-    /// *v.push_front() = 1;
-    /// assert!(v.is_full());
+    /// let mut buf = FixedVecDeque::<[(u32, &str); 4]>::new();
+    /// buf.extend([(1, "a"), (2, "b"), (5, "c"), (6, "d")]);
+    ///
+    /// let expired = buf.drain_while_front(|e| e.0 < 5).collect::<Vec<_>>();
+    /// assert_eq!(expired, [(1, "a"), (2, "b")]);
+    /// assert_eq!(buf, [(5, "c"), (6, "d")]);
     /// ```
-    pub fn is_full(&self) -> bool {
-        self.len == T::size()
+    pub fn drain_while_front<F>(&mut self, pred: F) -> DrainWhile<'_, T, F>
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        DrainWhile {
+            deq: self,
+            pred,
+            done: false,
+        }
     }
 
-    /// Returns the number of elements in the `FixedVecDeque`.
+    /// Removes an element from anywhere in the `FixedVecDeque` and returns it by value, replacing
+    /// it with the last element.
+    ///
+    /// This is like [`swap_remove_back`], except that the removed element is moved out and its
+    /// slot is left holding `Default::default()`.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
     ///
     /// # Examples
     ///
     /// ```
-    /// # extern crate fixed_vec_deque;
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut v = FixedVecDeque::<[u32; 2]>::new();
-    /// assert_eq!(v.len(), 0);
-    /// *v.push_back() = 1;
-    /// assert_eq!(v.len(), 1);
-    /// *v.push_back() = 1;
-    /// assert_eq!(v.len(), 2);
+    /// let mut buf = FixedVecDeque::<[String; 4]>::new();
+    /// assert_eq!(buf.swap_take_back(0), None);
+    /// buf.push_back().push_str("a");
+    /// buf.push_back().push_str("b");
+    /// buf.push_back().push_str("c");
+    ///
+    /// assert_eq!(buf.swap_take_back(0).as_deref(), Some("a"));
+    /// assert_eq!(buf, ["c", "b"]);
     /// ```
-    pub fn len(&self) -> usize {
-        self.len
+    ///
+    /// [`swap_remove_back`]: struct.FixedVecDeque.html#method.swap_remove_back
+    pub fn swap_take_back(&mut self, index: usize) -> Option<T::Item> {
+        self.swap_remove_back(index).map(mem::take)
     }
 
-    /// Returns the number of elements the `FixedVecDeque` can hold.
+    /// Removes an element from anywhere in the `FixedVecDeque` and returns it by value, replacing
+    /// it with the first element.
+    ///
+    /// This is like [`swap_remove_front`], except that the removed element is moved out and its
+    /// slot is left holding `Default::default()`.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let buf = FixedVecDeque::<[u32; 16]>::new();
-    /// assert_eq!(buf.capacity(), 16);
+    /// let mut buf = FixedVecDeque::<[String; 4]>::new();
+    /// assert_eq!(buf.swap_take_front(0), None);
+    /// buf.push_back().push_str("a");
+    /// buf.push_back().push_str("b");
+    /// buf.push_back().push_str("c");
+    ///
+    /// assert_eq!(buf.swap_take_front(2).as_deref(), Some("c"));
+    /// assert_eq!(buf, ["b", "a"]);
     /// ```
-    #[inline]
-    pub fn capacity(&self) -> usize {
-        T::size()
+    ///
+    /// [`swap_remove_front`]: struct.FixedVecDeque.html#method.swap_remove_front
+    pub fn swap_take_front(&mut self, index: usize) -> Option<T::Item> {
+        self.swap_remove_front(index).map(mem::take)
     }
 
-    /// Shortens the `FixedVecDeque`, causing excess elements to be unused.
+    /// Clears the `FixedVecDeque` and overwrites every slot in the underlying buffer with
+    /// `Default::default()`.
     ///
-    /// If `len` is greater than the `FixedVecDeque`'s current length, this has no
-    /// effect.
+    /// Unlike [`clear`], this makes sure that no stored values are left behind in the buffer.
+    /// Note that this is O(capacity) rather than O(1).
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
-    /// *buf.push_back() = 5;
-    /// *buf.push_back() = 10;
-    /// *buf.push_back() = 15;
-    /// assert_eq!(buf, [5, 10, 15]);
-    /// buf.truncate(1);
-    /// assert_eq!(buf, [5]);
+    /// let mut v = FixedVecDeque::<[u32; 2]>::new();
+    /// *v.push_back() = 1;
+    /// v.reset();
+    /// assert!(v.is_empty());
+    /// assert_eq!(*v.push_back(), 0);
     /// ```
-    pub fn truncate(&mut self, len: usize) {
-        if len < self.len {
-            self.head = T::wrap_sub(self.head, self.len - len);
-            self.len = len;
+    ///
+    /// [`clear`]: struct.FixedVecDeque.html#method.clear
+    pub fn reset(&mut self) {
+        for elem in unsafe { self.buffer_as_mut_slice() } {
+            *elem = T::Item::default();
         }
+
+        self.clear();
     }
 
-    /// Provides a reference to the front element, or `None` if the `FixedVecDeque` is
-    /// empty.
+    /// Shortens the `FixedVecDeque` like [`truncate`], but also replaces every removed element
+    /// with its default value.
+    ///
+    /// This makes sure that any resources held by the removed elements are released immediately,
+    /// instead of when their slots are eventually overwritten.
+    ///
+    /// If `len` is greater than the `FixedVecDeque`'s current length, this has no effect.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
-    /// assert_eq!(d.front(), None);
+    /// let mut buf = FixedVecDeque::<[String; 4]>::new();
+    /// buf.extend(["a", "b", "c"].map(String::from));
     ///
-    /// *d.push_back() = 1;
-    /// *d.push_back() = 2;
-    /// assert_eq!(d.front(), Some(&1));
+    /// buf.truncate_drop(1);
+    /// assert_eq!(buf, ["a"]);
+    /// assert_eq!(*buf.push_back(), "");
     /// ```
-    pub fn front(&self) -> Option<&T::Item> {
-        if self.is_empty() {
-            return None;
+    ///
+    /// [`truncate`]: struct.FixedVecDeque.html#method.truncate
+    pub fn truncate_drop(&mut self, len: usize) {
+        if len >= self.len {
+            return;
         }
 
-        let front = self.tail();
-        Some(unsafe { self.buffer(front) })
+        let removed = self.len - len;
+        self.truncate(len);
+
+        for i in 0..removed {
+            let index = T::wrap_add(self.head, i);
+            *unsafe { self.buffer_mut(index) } = T::Item::default();
+        }
     }
 
-    /// Provides a mutable reference to the front element, or `None` if the `FixedVecDeque` is
-    /// empty.
+    /// Adds default values to the back of the `FixedVecDeque` until it is full.
+    ///
+    /// Every added element is assigned `Default::default()`, so no stale values become live.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2]);
     ///
-    /// assert_eq!(d.front_mut(), None);
+    /// buf.fill_to_capacity();
+    /// assert!(buf.is_full());
+    /// assert_eq!(buf, [1, 2, 0, 0]);
+    /// ```
+    pub fn fill_to_capacity(&mut self) {
+        while self.len < T::size() {
+            *self.push_back() = T::Item::default();
+        }
+    }
+
+    /// Converts the `FixedVecDeque` into a vector by moving out its live elements, front to back.
     ///
-    /// *d.push_back() = 1;
-    /// *d.push_back() = 2;
+    /// # Examples
     ///
-    /// match d.front_mut() {
-    ///     Some(x) => *x = 9,
-    ///     None => (),
-    /// }
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// assert_eq!(d.front(), Some(&9));
-    /// assert_eq!(d.back(), Some(&2));
+    /// let mut buf = FixedVecDeque::<[u32; 3]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    /// assert_eq!(buf.into_vec(), vec![2, 3, 4]);
     /// ```
-    pub fn front_mut(&mut self) -> Option<&mut T::Item> {
-        if self.is_empty() {
-            return None;
-        }
-
-        let front = self.tail();
-        Some(unsafe { self.buffer_mut(front) })
+    pub fn into_vec(mut self) -> Vec<T::Item> {
+        self.drain().collect()
     }
 
-    /// Provides a reference to the back element, or `None` if the `FixedVecDeque` is
-    /// empty.
+    /// Constructs a `FixedVecDeque` from the elements of an iterator.
+    ///
+    /// Unlike the `FromIterator` implementation, which overwrites the oldest elements once the
+    /// buffer is full, this returns an error if the iterator produces more elements than the
+    /// `FixedVecDeque` can hold. The iterator is not consumed beyond the first element which
+    /// doesn't fit.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the iterator produces more than `capacity()` elements.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
-    ///
-    /// assert_eq!(d.back(), None);
+    /// let buf = FixedVecDeque::<[u32; 3]>::try_from_iter(1..=3).unwrap();
+    /// assert_eq!(buf, [1, 2, 3]);
     ///
-    /// *d.push_back() = 1;
-    /// *d.push_back() = 2;
-    /// assert_eq!(d.back(), Some(&2));
+    /// let err = FixedVecDeque::<[u32; 3]>::try_from_iter(1..=4).unwrap_err();
+    /// assert_eq!(err.capacity(), 3);
     /// ```
-    pub fn back(&self) -> Option<&T::Item> {
-        if self.is_empty() {
-            return None;
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, CapacityError>
+    where
+        I: IntoIterator<Item = T::Item>,
+    {
+        let mut deq = Self::new();
+
+        for elt in iter {
+            if deq.is_full() {
+                return Err(CapacityError::new(T::size()));
+            }
+
+            *deq.push_back() = elt;
         }
 
-        let back = T::wrap_sub(self.head, 1);
-        Some(unsafe { self.buffer(back) })
+        Ok(deq)
     }
 
-    /// Provides a mutable reference to the back element, or `None` if the
-    /// `FixedVecDeque` is empty.
+    /// Constructs a `FixedVecDeque` by cloning the elements of `front` followed by the elements of
+    /// `back`.
+    ///
+    /// This is the inverse of [`as_slices`]. The elements are stored contiguously at the start of
+    /// the underlying buffer, and the remaining slots are initialized using `Default::default()`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the combined length of the slices is greater than `capacity()`.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
-    ///
-    /// assert_eq!(d.back(), None);
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
     ///
-    /// *d.push_back() = 1;
-    /// *d.push_back() = 2;
+    /// let (a, b) = buf.as_slices();
+    /// let copy = FixedVecDeque::<[u32; 4]>::from_slices(a, b).unwrap();
+    /// assert_eq!(copy, buf);
     ///
-    /// match d.back_mut() {
-    ///     Some(x) => *x = 9,
-    ///     None => (),
-    /// }
-    /// assert_eq!(d.back(), Some(&9));
+    /// let err = FixedVecDeque::<[u32; 4]>::from_slices(&[1, 2, 3], &[4, 5]).unwrap_err();
+    /// assert_eq!(err.capacity(), 4);
     /// ```
-    pub fn back_mut(&mut self) -> Option<&mut T::Item> {
-        if self.is_empty() {
-            return None;
+    ///
+    /// [`as_slices`]: struct.FixedVecDeque.html#method.as_slices
+    pub fn from_slices(front: &[T::Item], back: &[T::Item]) -> Result<Self, CapacityError>
+    where
+        T::Item: Clone,
+    {
+        let len = front.len() + back.len();
+
+        if len > T::size() {
+            return Err(CapacityError::new(T::size()));
         }
 
-        let back = T::wrap_sub(self.head, 1);
-        Some(unsafe { self.buffer_mut(back) })
+        let data = T::from_fn(|i| {
+            if i < front.len() {
+                front[i].clone()
+            } else if i < len {
+                back[i - front.len()].clone()
+            } else {
+                T::Item::default()
+            }
+        });
+
+        Ok(FixedVecDeque {
+            head: T::wrap_add(0, len),
+            len,
+            #[cfg(feature = "overwrite-count")]
+            overwrites: 0,
+            data,
+        })
     }
 
-    /// Prepends an element to the `FixedVecDeque`.
+    /// Initialize stored data using `Default::default()`
+    fn data_from_default() -> T {
+        T::from_fn(|_| T::Item::default())
+    }
+}
+
+impl<T> FixedVecDeque<T>
+where
+    T: Array,
+{
+    /// Construct a new fixed ring buffer where every element is initialized by calling `f` with
+    /// its index, of which the first `len` elements are live.
+    ///
+    /// Like `std::array::from_fn`, `f` is called once for every index in the underlying buffer in
+    /// order, so the elements which are not live are used as storage by later pushes.
     ///
     /// # Panics
     ///
-    /// Calling this function will panic if the circular buffer is zero-sized.
+    /// Panics if `len` is greater than the capacity of the `FixedVecDeque`.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut d = FixedVecDeque::<[u32; 3]>::new();
+    /// let mut deq = FixedVecDeque::<[u32; 6]>::from_fn(4, |i| (i * i) as u32);
+    /// assert_eq!(deq, [0, 1, 4, 9]);
     ///
-    /// assert_eq!(d.front(), None);
-    /// assert_eq!(d.back(), None);
+    /// // the remaining elements were initialized by the closure as well.
+    /// deq.push_back();
+    /// assert_eq!(deq, [0, 1, 4, 9, 16]);
+    /// ```
+    pub fn from_fn<F>(len: usize, f: F) -> Self
+    where
+        F: FnMut(usize) -> T::Item,
+    {
+        assert!(len <= T::size(), "length beyond capacity");
+
+        FixedVecDeque {
+            head: if len == T::size() { 0 } else { len },
+            len,
+            #[cfg(feature = "overwrite-count")]
+            overwrites: 0,
+            data: T::from_fn(f),
+        }
+    }
+
+    /// Constructs a `FixedVecDeque` from an existing backing store and cursors, without copying
+    /// any elements.
     ///
-    /// *d.push_front() = 1;
-    /// assert_eq!(d.front(), Some(&1));
-    /// assert_eq!(d.back(), Some(&1));
+    /// `head` is the physical index at which the next element will be written by
+    /// [`push_back`], and the live elements are the `len` elements stored before it, wrapping
+    /// around the end of the underlying buffer. This is the inverse of [`head_index`] and
+    /// [`len`], and can be used together with a borrowed array like `&mut [T; N]` to adopt memory
+    /// which is owned elsewhere as a ring buffer.
     ///
-    /// *d.push_front() = 2;
-    /// assert_eq!(d.front(), Some(&2));
-    /// assert_eq!(d.back(), Some(&1));
+    /// # Safety
     ///
-    /// *d.push_front() = 3;
-    /// assert_eq!(d.front(), Some(&3));
-    /// assert_eq!(d.back(), Some(&1));
+    /// The caller must ensure that:
+    ///
+    /// * `head` is less than the capacity of the `FixedVecDeque`, or zero if the capacity is
+    ///   zero.
+    /// * `len` is less than or equal to the capacity of the `FixedVecDeque`.
+    ///
+    /// Every element of `data` is already initialized, so any `head` and `len` satisfying this
+    /// is valid. Violating it causes out of bounds accesses into the underlying buffer. This is
+    /// checked with debug assertions.
+    ///
+    /// # Examples
     ///
-    /// *d.push_front() = 4;
-    /// assert_eq!(d.front(), Some(&4));
-    /// assert_eq!(d.back(), Some(&2));
     /// ```
-    pub fn push_front(&mut self) -> &mut T::Item {
-        assert!(T::size() > 0, "Cannot add to an empty deque");
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let buf = unsafe { FixedVecDeque::from_raw_parts([4, 5, 0, 1, 2, 3], 2, 5) };
+    /// assert_eq!(buf.as_slices(), (&[1, 2, 3][..], &[4, 5][..]));
+    ///
+    /// let mut storage = [1, 2, 3, 4];
+    ///
+    /// {
+    ///     let mut buf = unsafe { FixedVecDeque::from_raw_parts(&mut storage, 0, 4) };
+    ///     *buf.push_back() = 5;
+    ///     assert_eq!(buf, [2, 3, 4, 5]);
+    /// }
+    ///
+    /// assert_eq!(storage, [5, 2, 3, 4]);
+    /// ```
+    ///
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    /// [`head_index`]: struct.FixedVecDeque.html#method.head_index
+    /// [`len`]: struct.FixedVecDeque.html#method.len
+    pub unsafe fn from_raw_parts(data: T, head: usize, len: usize) -> Self {
+        debug_assert!(head < T::size() || head == 0, "head {} out of bounds", head);
+        debug_assert!(len <= T::size(), "len {} out of bounds", len);
 
-        // overwriting existing elements.
-        if self.len == T::size() {
-            self.head = T::wrap_sub(self.head, 1);
-            let front = self.head;
-            return unsafe { self.buffer_mut(front) };
+        FixedVecDeque {
+            head,
+            len,
+            #[cfg(feature = "overwrite-count")]
+            overwrites: 0,
+            data,
         }
-
-        self.len += 1;
-        let front = self.tail();
-        unsafe { self.buffer_mut(front) }
     }
 
-    /// Removes the first element and returns it, or `None` if the `FixedVecDeque` is
-    /// empty.
+    /// Returns `true` if the `FixedVecDeque` is empty.
     ///
     /// # Examples
     ///
     /// ```
+    /// # extern crate fixed_vec_deque;
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
-    /// *d.push_back() = 1;
-    /// *d.push_back() = 2;
-    ///
-    /// assert_eq!(d.pop_front(), Some(&mut 1));
-    /// assert_eq!(d.pop_front(), Some(&mut 2));
-    /// assert_eq!(d.pop_front(), None);
+    /// let mut v = FixedVecDeque::<[u32; 1]>::new();
+    /// assert!(v.is_empty());
+    /// *v.push_front() = 1;
+    /// assert!(!v.is_empty());
     /// ```
-    pub fn pop_front(&mut self) -> Option<&mut T::Item> {
-        if self.is_empty() {
-            return None;
-        }
-
-        let tail = self.tail();
-        self.len -= 1;
-        unsafe { Some(self.buffer_mut(tail)) }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
-    /// Appends an element to the back of the `FixedVecDeque` by returning a mutable reference that
-    /// can be modified to it.
+    /// Returns `true` if the `FixedVecDeque` is full.
     ///
-    /// Note: this might potentially remove elements from the head, unless they have been read.
+    /// Writing to a queue that is full will overwrite existing elements.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Calling this function will panic if the circular buffer is zero-sized.
+    /// ```
+    /// # extern crate fixed_vec_deque;
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut v = FixedVecDeque::<[u32; 1]>::new();
+    /// assert!(!v.is_full());
+    /// *v.push_front() = 1;
+    /// assert!(v.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.len == T::size()
+    }
+
+    /// Returns the number of elements in the `FixedVecDeque`.
     ///
     /// # Examples
     ///
     /// ```
+    /// # extern crate fixed_vec_deque;
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[u32; 2]>::new();
-    /// assert_eq!(buf.back(), None);
-    /// assert_eq!(buf.front(), None);
+    /// let mut v = FixedVecDeque::<[u32; 2]>::new();
+    /// assert_eq!(v.len(), 0);
+    /// *v.push_back() = 1;
+    /// assert_eq!(v.len(), 1);
+    /// *v.push_back() = 1;
+    /// assert_eq!(v.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of elements the `FixedVecDeque` can hold.
     ///
-    /// *buf.push_back() = 1;
+    /// # Examples
     ///
-    /// assert_eq!(buf.front(), Some(&1));
-    /// assert_eq!(buf.back(), Some(&1));
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// *buf.push_back() = 2;
+    /// let buf = FixedVecDeque::<[u32; 16]>::new();
+    /// assert_eq!(buf.capacity(), 16);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        T::size()
+    }
+
+    /// Returns the number of elements which can be added to the `FixedVecDeque` before it is full
+    /// and starts overwriting existing elements.
     ///
-    /// assert_eq!(buf.front(), Some(&1));
-    /// assert_eq!(buf.back(), Some(&2));
+    /// # Examples
     ///
-    /// *buf.push_back() = 3;
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// assert_eq!(buf.front(), Some(&2));
-    /// assert_eq!(buf.back(), Some(&3));
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.remaining_capacity(), 4);
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.remaining_capacity(), 1);
+    /// buf.extend([4, 5]);
+    /// assert_eq!(buf.remaining_capacity(), 0);
     /// ```
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        T::size() - self.len
+    }
+
+    /// Returns the number of elements which have been overwritten by adding elements to a full
+    /// `FixedVecDeque`, since it was constructed or last cleared.
+    ///
+    /// This counts elements overwritten by [`push_back`], [`push_front`] and [`push_back_n`],
+    /// including when they are called by other methods like `extend`, as well as elements evicted
+    /// by [`insert_sorted`]. Elements which are removed explicitly, like through [`pop_front`] or
+    /// by [`merge_sorted`] making room, are not counted.
+    ///
+    /// This is only available with the `overwrite-count` feature, since keeping track of it costs
+    /// an extra `u64` in every `FixedVecDeque` and a check on every push.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[u32; 1]>::new();
-    /// assert_eq!(buf.back(), None);
-    /// assert_eq!(buf.front(), None);
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    /// assert_eq!(buf.overwrite_count(), 0);
     ///
-    /// *buf.push_back() = 1;
+    /// buf.extend([5, 6]);
+    /// assert_eq!(buf.overwrite_count(), 2);
     ///
-    /// assert_eq!(buf.front(), Some(&1));
-    /// assert_eq!(buf.back(), Some(&1));
+    /// buf.clear();
+    /// assert_eq!(buf.overwrite_count(), 0);
+    /// ```
     ///
-    /// *buf.push_back() = 2;
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    /// [`push_front`]: struct.FixedVecDeque.html#method.push_front
+    /// [`push_back_n`]: struct.FixedVecDeque.html#method.push_back_n
+    /// [`insert_sorted`]: struct.FixedVecDeque.html#method.insert_sorted
+    /// [`pop_front`]: struct.FixedVecDeque.html#method.pop_front
+    /// [`merge_sorted`]: struct.FixedVecDeque.html#method.merge_sorted
+    #[cfg(feature = "overwrite-count")]
+    #[inline]
+    pub fn overwrite_count(&self) -> u64 {
+        self.overwrites
+    }
+
+    /// Returns the physical index in the underlying buffer at which the next element will be
+    /// written by [`push_back`].
     ///
-    /// assert_eq!(buf.front(), Some(&2));
-    /// assert_eq!(buf.back(), Some(&2));
+    /// The element at the back of the queue is stored immediately before it, wrapping around the
+    /// end of the underlying buffer.
     ///
-    /// buf.pop_back();
+    /// # Examples
     ///
-    /// assert!(buf.is_empty());
-    /// assert_eq!(buf.back(), None);
-    /// assert_eq!(buf.front(), None);
     /// ```
-    pub fn push_back(&mut self) -> &mut T::Item {
-        assert!(T::size() > 0, "Cannot add to an empty deque");
-
-        let head = self.head;
-        self.head = T::wrap_add(self.head, 1);
-
-        if self.len < T::size() {
-            self.len += 1;
-        }
-
-        unsafe { self.buffer_mut(head) }
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.head_index(), 0);
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.head_index(), 3);
+    /// *buf.push_back() = 4;
+    /// assert_eq!(buf.head_index(), 0);
+    /// ```
+    ///
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    #[inline]
+    pub fn head_index(&self) -> usize {
+        self.head
     }
 
-    /// Removes the last element from the `FixedVecDeque` and returns a reference to it, or `None`
-    /// if it is empty.
+    /// Returns the physical index in the underlying buffer of the element at the front of the
+    /// queue.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[u32; 2]>::new();
-    /// assert_eq!(buf.pop_back(), None);
-    /// *buf.push_back() = 1;
-    /// *buf.push_back() = 3;
-    /// assert_eq!(buf.pop_back(), Some(&mut 3));
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.tail_index(), 0);
+    /// buf.extend([1, 2, 3, 4, 5]);
+    /// assert_eq!(buf.tail_index(), 1);
+    /// *buf.push_front() = 0;
+    /// assert_eq!(buf.tail_index(), 0);
     /// ```
-    pub fn pop_back(&mut self) -> Option<&mut T::Item> {
-        if self.is_empty() {
-            return None;
-        }
-
-        self.head = T::wrap_sub(self.head, 1);
-        self.len -= 1;
-        let head = self.head;
-        unsafe { Some(self.buffer_mut(head)) }
+    #[inline]
+    pub fn tail_index(&self) -> usize {
+        self.tail()
     }
 
-    /// Removes an element from anywhere in the `FixedVecDeque` and returns a mutable reference to
-    /// it, replacing it with the last element.
+    /// Sets the internal cursors of the `FixedVecDeque`, as returned by [`head_index`] and
+    /// [`len`].
     ///
-    /// This does not preserve ordering, but is O(1).
+    /// This can be used to restore a snapshot of the state of the deque.
     ///
-    /// Returns `None` if `index` is out of bounds.
+    /// # Safety
     ///
-    /// Element at index 0 is the front of the queue.
+    /// The caller must ensure that `head` is less than the capacity of the `FixedVecDeque` (or
+    /// zero if the capacity is zero), and that `len` is less than or equal to its capacity.
+    /// Violating this causes out of bounds accesses into the underlying buffer.
     ///
     /// # Examples
     ///
@@ -600,33 +880,33 @@ where
     /// use fixed_vec_deque::FixedVecDeque;
     ///
     /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
-    /// assert_eq!(buf.swap_remove_back(0), None);
-    /// *buf.push_back() = 1;
-    /// *buf.push_back() = 2;
-    /// *buf.push_back() = 3;
-    /// assert_eq!(buf, [1, 2, 3]);
+    /// buf.extend([1, 2, 3, 4, 5]);
+    /// let (head, len) = (buf.head_index(), buf.len());
     ///
-    /// assert_eq!(buf.swap_remove_back(0), Some(&mut 1));
-    /// assert_eq!(buf, [3, 2]);
+    /// buf.clear();
+    /// assert!(buf.is_empty());
+    ///
+    /// unsafe {
+    ///     buf.set_cursors(head, len);
+    /// }
+    ///
+    /// assert_eq!(buf, [2, 3, 4, 5]);
     /// ```
-    pub fn swap_remove_back(&mut self, index: usize) -> Option<&mut T::Item> {
-        let length = self.len();
-        if length > 0 && index < length - 1 {
-            self.swap(index, length - 1);
-        } else if index >= length {
-            return None;
-        }
-        self.pop_back()
+    ///
+    /// [`head_index`]: struct.FixedVecDeque.html#method.head_index
+    /// [`len`]: struct.FixedVecDeque.html#method.len
+    pub unsafe fn set_cursors(&mut self, head: usize, len: usize) {
+        debug_assert!(head < T::size() || head == 0, "head {} out of bounds", head);
+        debug_assert!(len <= T::size(), "len {} out of bounds", len);
+        self.head = head;
+        self.len = len;
     }
 
-    /// Removes an element from anywhere in the `FixedVecDeque` and returns a reference to it,
-    /// replacing it with the first element.
-    ///
-    /// This does not preserve ordering, but is O(1).
+    /// Checks that the internal cursors of the `FixedVecDeque` are consistent with its capacity.
     ///
-    /// Returns `None` if `index` is out of bounds.
-    ///
-    /// Element at index 0 is the front of the queue.
+    /// This verifies that the length doesn't exceed the capacity and that the head is in bounds
+    /// of the underlying buffer. It can only return `false` if the cursors have been corrupted,
+    /// such as through an incorrect call to [`set_cursors`].
     ///
     /// # Examples
     ///
@@ -634,31 +914,26 @@ where
     /// use fixed_vec_deque::FixedVecDeque;
     ///
     /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
-    /// assert_eq!(buf.swap_remove_front(0), None);
-    /// *buf.push_back() = 1;
-    /// *buf.push_back() = 2;
-    /// *buf.push_back() = 3;
-    /// assert_eq!(buf, [1, 2, 3]);
+    /// assert!(buf.check_invariants());
     ///
-    /// assert_eq!(buf.swap_remove_front(2), Some(&mut 3));
-    /// assert_eq!(buf, [2, 1]);
+    /// buf.extend([1, 2, 3, 4, 5]);
+    /// assert!(buf.check_invariants());
     /// ```
-    pub fn swap_remove_front(&mut self, index: usize) -> Option<&mut T::Item> {
-        let length = self.len();
-        if length > 0 && index < length && index != 0 {
-            self.swap(index, 0);
-        } else if index >= length {
-            return None;
+    ///
+    /// [`set_cursors`]: struct.FixedVecDeque.html#method.set_cursors
+    pub fn check_invariants(&self) -> bool {
+        if T::size() == 0 {
+            return self.head == 0 && self.len == 0;
         }
-        self.pop_front()
+
+        // NB: the tail isn't stored but derived from the head and the length, so it's always
+        // consistent with them once they're in bounds.
+        self.len <= T::size() && self.head < T::size()
     }
 
-    /// Removes and returns the element at `index` from the `VecDeque`.
-    /// Whichever end is closer to the removal point will be moved to make
-    /// room, and all the affected elements will be moved to new positions.
-    /// Returns `None` if `index` is out of bounds.
+    /// Asserts that [`check_invariants`] holds in debug builds.
     ///
-    /// Element at index 0 is the front of the queue.
+    /// This does nothing in release builds.
     ///
     /// # Examples
     ///
@@ -666,228 +941,54 @@ where
     /// use fixed_vec_deque::FixedVecDeque;
     ///
     /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
-    /// *buf.push_back() = 1;
-    /// *buf.push_back() = 2;
-    /// *buf.push_back() = 3;
-    /// assert_eq!(buf, [1, 2, 3]);
-    ///
-    /// assert_eq!(buf.remove(1), Some(&mut 2));
-    /// assert_eq!(buf, [1, 3]);
+    /// buf.extend([1, 2, 3]);
+    /// buf.debug_check_invariants();
     /// ```
-    pub fn remove(&mut self, index: usize) -> Option<&mut T::Item>
-    where
-        T::Item: fmt::Debug,
-    {
-        // if empty, nothing to do.
-        if T::size() == 0 || index >= self.len {
-            return None;
-        }
+    ///
+    /// [`check_invariants`]: struct.FixedVecDeque.html#method.check_invariants
+    #[inline]
+    pub fn debug_check_invariants(&self) {
+        debug_assert!(
+            self.check_invariants(),
+            "invariants violated: head is {}, len is {} and capacity is {}",
+            self.head,
+            self.len,
+            T::size()
+        );
+    }
 
-        // There are three main cases:
-        //  Elements are contiguous
-        //  Elements are discontiguous and the removal is in the tail section
-        //  Elements are discontiguous and the removal is in the head section
-        //      - special case when elements are technically contiguous,
-        //        but self.head = 0
-        //
-        // For each of those there are two more cases:
-        //  Insert is closer to tail
-        //  Insert is closer to head
-        //
-        // Key: H - self.head
-        //      T - self.tail
-        //      o - Valid element
-        //      x - Element marked for removal
-        //      R - Indicates element that is being removed
-        //      M - Indicates element was moved
-
-        let idx = self.ptr_index(index);
-        let head = self.head;
-        let tail = self.tail();
-
-        let tmp = unsafe { self.buffer_read(idx) };
-
-        let distance_to_tail = index;
-        let distance_to_head = self.len() - index;
-
-        let contiguous = self.is_contiguous();
-
-        let idx = match (
-            contiguous,
-            distance_to_tail <= distance_to_head,
-            idx >= tail,
-        ) {
-            (true, true, _) => {
-                unsafe {
-                    // contiguous, remove closer to tail:
-                    //
-                    //             T   R         H
-                    //      [. . . o o x o o o o . . . . . .]
-                    //
-                    //               T           H
-                    //      [. . . . o o o o o o . . . . . .]
-                    //               M M
-
-                    self.copy(tail + 1, tail, index);
-                    tail
-                }
-            }
-            (true, false, _) => {
-                unsafe {
-                    // contiguous, remove closer to head:
-                    //
-                    //             T       R     H
-                    //      [. . . o o o o x o o . . . . . .]
-                    //
-                    //             T           H
-                    //      [. . . o o o o o o . . . . . . .]
-                    //                     M M
-
-                    self.copy(idx, idx + 1, head - idx - 1);
-                    self.head -= 1;
-                    head
-                }
-            }
-            (false, true, true) => {
-                unsafe {
-                    // discontiguous, remove closer to tail, tail section:
-                    //
-                    //                   H         T   R
-                    //      [o o o o o o . . . . . o o x o o]
-                    //
-                    //                   H           T
-                    //      [o o o o o o . . . . . . o o o o]
-                    //                               M M
-
-                    self.copy(tail + 1, tail, index);
-                    tail
-                }
-            }
-            (false, false, false) => {
-                unsafe {
-                    // discontiguous, remove closer to head, head section:
-                    //
-                    //               R     H           T
-                    //      [o o o o x o o . . . . . . o o o]
-                    //
-                    //                   H             T
-                    //      [o o o o o o . . . . . . . o o o]
-                    //               M M
-
-                    self.copy(idx, idx + 1, head - idx - 1);
-                    self.head -= 1;
-                    head
-                }
-            }
-            (false, false, true) => {
-                unsafe {
-                    // discontiguous, remove closer to head, tail section:
-                    //
-                    //             H           T         R
-                    //      [o o o . . . . . . o o o o o x o]
-                    //
-                    //           H             T
-                    //      [o o . . . . . . . o o o o o o o]
-                    //       M M                         M M
-                    //
-                    // or quasi-discontiguous, remove next to head, tail section:
-                    //
-                    //       H                 T         R
-                    //      [. . . . . . . . . o o o o o x o]
-                    //
-                    //                         T           H
-                    //      [. . . . . . . . . o o o o o o .]
-                    //                                   M
-
-                    // draw in elements in the tail section
-                    self.copy(idx, idx + 1, T::size() - idx - 1);
-
-                    // Prevents underflow.
-                    if head != 0 {
-                        // copy first element into empty spot
-                        self.copy(T::size() - 1, 0, 1);
-
-                        // move elements in the head section backwards
-                        self.copy(0, 1, head - 1);
-                    }
-
-                    self.head = T::wrap_sub(self.head, 1);
-                    head
-                }
-            }
-            (false, true, false) => {
-                unsafe {
-                    // discontiguous, remove closer to tail, head section:
-                    //
-                    //           R               H     T
-                    //      [o o x o o o o o o o . . . o o o]
-                    //
-                    //                           H       T
-                    //      [o o o o o o o o o o . . . . o o]
-                    //       M M M                       M M
-
-                    // draw in elements up to idx
-                    self.copy(1, 0, idx);
-
-                    // copy last element into empty spot
-                    self.copy(0, T::size() - 1, 1);
-
-                    // move elements from tail to end forward, excluding the last one
-                    self.copy(tail + 1, tail, T::size() - tail - 1);
-
-                    tail
-                }
-            }
-        };
-
-        self.len -= 1;
-
-        unsafe {
-            // write temporary into shifted location since we need a stable memory location for it!
-            self.buffer_write(idx, tmp);
-            Some(self.buffer_mut(idx))
-        }
-    }
-
-    /// Retains only the elements specified by the predicate.
+    /// Returns a raw pointer to the start of the underlying buffer.
     ///
-    /// In other words, remove all elements `e` such that `f(&e)` returns false.
-    /// This method operates in place and preserves the order of the retained
-    /// elements.
+    /// Note that the buffer is a ring, so the front of the queue is not necessarily stored at the
+    /// start of it. The live elements are the [`len`] elements starting at [`tail_index`],
+    /// wrapping around the end of the buffer at [`capacity`].
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[usize; 8]>::new();
-    /// buf.extend(1..5);
-    /// buf.retain(|&x| x % 2 == 0);
-    /// assert_eq!(buf, [2, 4]);
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
+    ///
+    /// let ptr = buf.as_ptr();
+    /// let front = unsafe { *ptr.add(buf.tail_index()) };
+    /// assert_eq!(front, 2);
     /// ```
-    pub fn retain<F>(&mut self, mut f: F)
-    where
-        F: FnMut(&T::Item) -> bool,
-    {
-        let len = self.len();
-        let mut del = 0;
-
-        for i in 0..len {
-            let off = self.ptr_index(i);
-
-            if !f(unsafe { self.buffer(off) }) {
-                del += 1;
-            } else if del > 0 {
-                self.swap(i - del, i);
-            }
-        }
-
-        if del > 0 {
-            self.truncate(len - del);
-        }
+    ///
+    /// [`len`]: struct.FixedVecDeque.html#method.len
+    /// [`tail_index`]: struct.FixedVecDeque.html#method.tail_index
+    /// [`capacity`]: struct.FixedVecDeque.html#method.capacity
+    #[inline]
+    pub fn as_ptr(&self) -> *const T::Item {
+        self.data.ptr()
     }
 
-    /// Returns a front-to-back iterator.
+    /// Returns an unsafe mutable pointer to the start of the underlying buffer.
+    ///
+    /// Note that the buffer is a ring, so the front of the queue is not necessarily stored at the
+    /// start of it. The live elements are the [`len`] elements starting at [`tail_index`],
+    /// wrapping around the end of the buffer at [`capacity`].
     ///
     /// # Examples
     ///
@@ -895,24 +996,26 @@ where
     /// use fixed_vec_deque::FixedVecDeque;
     ///
     /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
-    /// *buf.push_back() = 5;
-    /// *buf.push_back() = 3;
-    /// *buf.push_back() = 4;
+    /// buf.extend([1, 2, 3, 4, 5]);
     ///
-    /// let b: &[_] = &[&5, &3, &4];
-    /// let c: Vec<&u32> = buf.iter().collect();
-    /// assert_eq!(&c[..], b);
+    /// let tail = buf.tail_index();
+    /// let ptr = buf.as_mut_ptr();
+    /// unsafe { *ptr.add(tail) = 10 };
+    /// assert_eq!(buf, [10, 3, 4, 5]);
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
-        Iter {
-            data: self.data.ptr(),
-            head: self.head,
-            len: self.len,
-            marker: marker::PhantomData,
-        }
+    ///
+    /// [`len`]: struct.FixedVecDeque.html#method.len
+    /// [`tail_index`]: struct.FixedVecDeque.html#method.tail_index
+    /// [`capacity`]: struct.FixedVecDeque.html#method.capacity
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T::Item {
+        self.data.ptr_mut()
     }
 
-    /// Returns a front-to-back iterator that returns mutable references.
+    /// Shortens the `FixedVecDeque`, causing excess elements to be unused.
+    ///
+    /// If `len` is greater than the `FixedVecDeque`'s current length, this has no
+    /// effect.
     ///
     /// # Examples
     ///
@@ -921,197 +1024,185 @@ where
     ///
     /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
     /// *buf.push_back() = 5;
-    /// *buf.push_back() = 3;
-    /// *buf.push_back() = 4;
-    /// for num in buf.iter_mut() {
-    ///     *num = *num - 2;
-    /// }
-    /// let b: &[_] = &[&mut 3, &mut 1, &mut 2];
-    /// assert_eq!(&buf.iter_mut().collect::<Vec<&mut u32>>()[..], b);
+    /// *buf.push_back() = 10;
+    /// *buf.push_back() = 15;
+    /// assert_eq!(buf, [5, 10, 15]);
+    /// buf.truncate(1);
+    /// assert_eq!(buf, [5]);
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        IterMut {
-            data: self.data.ptr_mut(),
-            head: self.head,
-            len: self.len,
-            marker: marker::PhantomData,
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.head = T::wrap_sub(self.head, self.len - len);
+            self.len = len;
         }
     }
 
-    /// Clears the `FixedVecDeque`.
+    /// Modifies the `FixedVecDeque` in-place so that `len()` is equal to `new_len`, either by
+    /// removing excess elements from the back or by appending elements produced by calling `f`
+    /// to the back.
+    ///
+    /// Unlike [`resize`], this doesn't require the elements to implement `Clone`.
+    ///
+    /// # Panics
     ///
-    /// The stored values will _not_ be deleted.
+    /// Panics if `new_len` is greater than the [`capacity`] of this buffer.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut v = FixedVecDeque::<[u32; 1]>::new();
-    /// *v.push_back() = 1;
-    /// v.clear();
-    /// assert!(v.is_empty());
-    /// ```
-    #[inline]
-    pub fn clear(&mut self) {
-        self.head = 0;
-        self.len = 0;
-    }
-
-    /// Returns `true` if the `FixedVecDeque` contains an element equal to the
-    /// given value.
+    /// let mut buf = FixedVecDeque::<[u32; 8]>::new();
+    /// buf.extend([5, 10, 15]);
     ///
-    /// # Examples
+    /// buf.resize_with(2, || 0);
+    /// assert_eq!(buf, [5, 10]);
     ///
+    /// let mut n = 0;
+    /// buf.resize_with(5, || {
+    ///     n += 1;
+    ///     n
+    /// });
+    /// assert_eq!(buf, [5, 10, 1, 2, 3]);
     /// ```
-    /// use fixed_vec_deque::FixedVecDeque;
-    ///
-    /// let mut vector = FixedVecDeque::<[u32; 4]>::new();
-    ///
-    /// *vector.push_back() = 0;
-    /// *vector.push_back() = 1;
     ///
-    /// assert_eq!(vector.contains(&1), true);
-    /// assert_eq!(vector.contains(&10), false);
-    /// ```
-    pub fn contains(&self, x: &T::Item) -> bool
+    /// [`resize`]: struct.FixedVecDeque.html#method.resize
+    /// [`capacity`]: struct.FixedVecDeque.html#method.capacity
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
     where
-        T::Item: PartialEq<T::Item>,
+        F: FnMut() -> T::Item,
     {
-        let (a, b) = self.as_slices();
-        a.contains(x) || b.contains(x)
+        assert!(new_len <= T::size(), "resize beyond capacity");
+
+        if new_len > self.len {
+            for _ in self.len..new_len {
+                *self.push_back() = f();
+            }
+        } else {
+            self.truncate(new_len);
+        }
     }
 
-    /// Returns a pair of slices which contain, in order, the contents of the `FixedVecDeque`.
+    /// Provides a reference to the front element, or `None` if the `FixedVecDeque` is
+    /// empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut vector = FixedVecDeque::<[u32; 6]>::new();
-    ///
-    /// *vector.push_back() = 0;
-    /// *vector.push_back() = 1;
-    ///
-    /// *vector.push_front() = 10;
-    /// *vector.push_front() = 9;
-    ///
-    /// vector.as_mut_slices().0[0] = 42;
-    /// vector.as_mut_slices().1[0] = 24;
+    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
+    /// assert_eq!(d.front(), None);
     ///
-    /// assert_eq!(vector.as_slices(), (&[42, 10][..], &[24, 1][..]));
+    /// *d.push_back() = 1;
+    /// *d.push_back() = 2;
+    /// assert_eq!(d.front(), Some(&1));
     /// ```
-    #[inline]
-    pub fn as_mut_slices(&mut self) -> (&mut [T::Item], &mut [T::Item]) {
-        if self.is_full() {
-            let head = self.head;
-            let buf = unsafe { self.buffer_as_mut_slice() };
-            let (left, right) = buf.split_at(head);
-            return (right, left);
+    pub fn front(&self) -> Option<&T::Item> {
+        if self.is_empty() {
+            return None;
         }
 
-        let head = self.head;
-        let tail = self.tail();
-        let buf = unsafe { self.buffer_as_mut_slice() };
-        RingSlices::ring_slices(buf, head, tail)
+        let front = self.tail();
+        Some(unsafe { self.buffer(front) })
     }
 
-    /// Returns a pair of slices which contain, in order, the contents of the `FixedVecDeque`.
+    /// Provides a mutable reference to the front element, or `None` if the `FixedVecDeque` is
+    /// empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut vector = FixedVecDeque::<[u32; 5]>::new();
+    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
     ///
-    /// *vector.push_back() = 1;
-    /// *vector.push_back() = 2;
-    /// *vector.push_back() = 3;
+    /// assert_eq!(d.front_mut(), None);
     ///
-    /// assert_eq!(vector.as_slices(), (&[1, 2, 3][..], &[][..]));
+    /// *d.push_back() = 1;
+    /// *d.push_back() = 2;
     ///
-    /// *vector.push_front() = 4;
-    /// *vector.push_front() = 5;
+    /// match d.front_mut() {
+    ///     Some(x) => *x = 9,
+    ///     None => (),
+    /// }
     ///
-    /// assert_eq!(vector.as_slices(), (&[5, 4][..], &[1, 2, 3][..]));
+    /// assert_eq!(d.front(), Some(&9));
+    /// assert_eq!(d.back(), Some(&2));
     /// ```
-    #[inline]
-    pub fn as_slices(&self) -> (&[T::Item], &[T::Item]) {
-        let buf = unsafe { self.buffer_as_slice() };
-
-        if self.len == T::size() {
-            let (left, right) = buf.split_at(self.head);
-            return (right, left);
+    pub fn front_mut(&mut self) -> Option<&mut T::Item> {
+        if self.is_empty() {
+            return None;
         }
 
-        let head = self.head;
-        let tail = T::wrap_sub(head, self.len);
-        RingSlices::ring_slices(buf, head, tail)
+        let front = self.tail();
+        Some(unsafe { self.buffer_mut(front) })
     }
 
-    /// Retrieves an element in the `FixedVecDeque` by index.
-    ///
-    /// Element at index 0 is the front of the queue.
+    /// Provides a reference to the back element, or `None` if the `FixedVecDeque` is
+    /// empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
-    /// *buf.push_back() = 3;
-    /// *buf.push_back() = 4;
-    /// *buf.push_back() = 5;
-    /// assert_eq!(buf.get(1), Some(&4));
+    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
+    ///
+    /// assert_eq!(d.back(), None);
+    ///
+    /// *d.push_back() = 1;
+    /// *d.push_back() = 2;
+    /// assert_eq!(d.back(), Some(&2));
     /// ```
-    pub fn get(&self, index: usize) -> Option<&T::Item> {
-        if index < self.len {
-            let off = self.ptr_index(index);
-            Some(unsafe { self.buffer(off) })
-        } else {
-            None
+    pub fn back(&self) -> Option<&T::Item> {
+        if self.is_empty() {
+            return None;
         }
+
+        let back = T::wrap_sub(self.head, 1);
+        Some(unsafe { self.buffer(back) })
     }
 
-    /// Retrieves an element in the `FixedVecDeque` mutably by index.
-    ///
-    /// Element at index 0 is the front of the queue.
+    /// Provides a mutable reference to the back element, or `None` if the
+    /// `FixedVecDeque` is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
-    /// *buf.push_back() = 3;
-    /// *buf.push_back() = 4;
-    /// *buf.push_back() = 5;
-    /// if let Some(elem) = buf.get_mut(1) {
-    ///     *elem = 7;
-    /// }
+    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
     ///
-    /// assert_eq!(buf[1], 7);
+    /// assert_eq!(d.back(), None);
+    ///
+    /// *d.push_back() = 1;
+    /// *d.push_back() = 2;
+    ///
+    /// match d.back_mut() {
+    ///     Some(x) => *x = 9,
+    ///     None => (),
+    /// }
+    /// assert_eq!(d.back(), Some(&9));
     /// ```
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T::Item> {
-        if index < self.len {
-            let off = self.ptr_index(index);
-            Some(unsafe { self.buffer_mut(off) })
-        } else {
-            None
+    pub fn back_mut(&mut self) -> Option<&mut T::Item> {
+        if self.is_empty() {
+            return None;
         }
+
+        let back = T::wrap_sub(self.head, 1);
+        Some(unsafe { self.buffer_mut(back) })
     }
 
-    /// Swaps elements at indices `i` and `j`.
-    ///
-    /// `i` and `j` may be equal.
+    /// Provides a mutable reference to the back element, calling [`push_back`] first if the
+    /// `FixedVecDeque` is empty.
     ///
-    /// Element at index 0 is the front of the queue.
+    /// Like with [`push_back`], an element added this way is not reset, so it holds whatever
+    /// value was previously stored in its slot.
     ///
     /// # Panics
     ///
-    /// Panics if either index is out of bounds.
+    /// Calling this function will panic if the circular buffer is zero-sized.
     ///
     /// # Examples
     ///
@@ -1119,689 +1210,7746 @@ where
     /// use fixed_vec_deque::FixedVecDeque;
     ///
     /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
-    /// *buf.push_back() = 3;
-    /// *buf.push_back() = 4;
-    /// *buf.push_back() = 5;
-    /// assert_eq!(buf, [3, 4, 5]);
-    /// buf.swap(0, 2);
-    /// assert_eq!(buf, [5, 4, 3]);
+    ///
+    /// *buf.back_or_push() += 1;
+    /// assert_eq!(buf, [1]);
+    ///
+    /// *buf.back_or_push() += 1;
+    /// assert_eq!(buf, [2]);
     /// ```
-    pub fn swap(&mut self, i: usize, j: usize) {
-        assert!(i < T::size());
-        assert!(j < T::size());
-        let ri = self.ptr_index(i);
-        let rj = self.ptr_index(j);
-        let d = self.data.ptr_mut();
-        unsafe { ptr::swap(d.add(ri), d.add(rj)) }
-    }
-
-    /// Turn `i`, which is a zero-based offset into a ptr index that wraps around the size of this
-    /// container.
-    #[inline]
-    fn ptr_index(&self, i: usize) -> usize {
-        T::wrap_add(self.tail(), i)
-    }
-
-    /// Get index of tail.
-    #[inline]
-    fn tail(&self) -> usize {
-        T::wrap_sub(self.head, self.len)
-    }
+    ///
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    pub fn back_or_push(&mut self) -> &mut T::Item {
+        if self.is_empty() {
+            return self.push_back();
+        }
 
-    /// Turn ptr into a slice
-    #[inline]
-    unsafe fn buffer_as_slice(&self) -> &[T::Item] {
-        slice::from_raw_parts(self.data.ptr(), T::size())
+        let back = T::wrap_sub(self.head, 1);
+        unsafe { self.buffer_mut(back) }
     }
 
-    /// Turn ptr into a mut slice
+    /// Returns the first element of the `FixedVecDeque`, or `None` if it is empty.
+    ///
+    /// This is an alias for [`front`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut v = FixedVecDeque::<[u32; 4]>::new();
+    /// v.extend([10, 40, 30]);
+    /// assert_eq!(Some(&10), v.first());
+    ///
+    /// let w = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(None, w.first());
+    /// ```
+    ///
+    /// [`front`]: struct.FixedVecDeque.html#method.front
     #[inline]
-    unsafe fn buffer_as_mut_slice(&mut self) -> &mut [T::Item] {
-        slice::from_raw_parts_mut(self.data.ptr_mut(), T::size())
+    pub fn first(&self) -> Option<&T::Item> {
+        self.front()
     }
 
-    /// Takes a reference of a value from the buffer.
+    /// Returns a mutable reference to the first element of the `FixedVecDeque`, or `None` if it
+    /// is empty.
+    ///
+    /// This is an alias for [`front_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut x = FixedVecDeque::<[u32; 4]>::new();
+    /// x.extend([0, 1, 2]);
+    ///
+    /// if let Some(first) = x.first_mut() {
+    ///     *first = 5;
+    /// }
+    ///
+    /// assert_eq!(x, [5, 1, 2]);
+    /// ```
+    ///
+    /// [`front_mut`]: struct.FixedVecDeque.html#method.front_mut
     #[inline]
-    unsafe fn buffer(&self, off: usize) -> &T::Item {
-        &*self.data.ptr().add(off)
+    pub fn first_mut(&mut self) -> Option<&mut T::Item> {
+        self.front_mut()
     }
 
-    /// Takes a mutable reference of a value from the buffer.
+    /// Returns the last element of the `FixedVecDeque`, or `None` if it is empty.
+    ///
+    /// This is an alias for [`back`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut v = FixedVecDeque::<[u32; 4]>::new();
+    /// v.extend([10, 40, 30]);
+    /// assert_eq!(Some(&30), v.last());
+    ///
+    /// let w = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(None, w.last());
+    /// ```
+    ///
+    /// [`back`]: struct.FixedVecDeque.html#method.back
     #[inline]
-    unsafe fn buffer_mut(&mut self, off: usize) -> &mut T::Item {
-        &mut *self.data.ptr_mut().add(off)
+    pub fn last(&self) -> Option<&T::Item> {
+        self.back()
     }
 
+    /// Returns a mutable reference to the last element of the `FixedVecDeque`, or `None` if it
+    /// is empty.
+    ///
+    /// This is an alias for [`back_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut x = FixedVecDeque::<[u32; 4]>::new();
+    /// x.extend([0, 1, 2]);
+    ///
+    /// if let Some(last) = x.last_mut() {
+    ///     *last = 10;
+    /// }
+    ///
+    /// assert_eq!(x, [0, 1, 10]);
+    /// ```
+    ///
+    /// [`back_mut`]: struct.FixedVecDeque.html#method.back_mut
     #[inline]
-    unsafe fn buffer_read(&mut self, off: usize) -> T::Item {
-        debug_assert!(off < T::size());
-        ptr::read(self.data.ptr().add(off))
+    pub fn last_mut(&mut self) -> Option<&mut T::Item> {
+        self.back_mut()
     }
 
-    #[inline]
-    unsafe fn buffer_write(&mut self, off: usize, data: T::Item) {
-        debug_assert!(off < T::size());
-        ptr::write(self.data.ptr_mut().add(off), data);
-    }
-
-    #[inline]
-    fn is_contiguous(&self) -> bool {
-        self.len != T::size() && self.tail() <= self.head
-    }
+    /// Prepends an element to the `FixedVecDeque`.
+    ///
+    /// # Panics
+    ///
+    /// Calling this function will panic if the circular buffer is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[u32; 3]>::new();
+    ///
+    /// assert_eq!(d.front(), None);
+    /// assert_eq!(d.back(), None);
+    ///
+    /// *d.push_front() = 1;
+    /// assert_eq!(d.front(), Some(&1));
+    /// assert_eq!(d.back(), Some(&1));
+    ///
+    /// *d.push_front() = 2;
+    /// assert_eq!(d.front(), Some(&2));
+    /// assert_eq!(d.back(), Some(&1));
+    ///
+    /// *d.push_front() = 3;
+    /// assert_eq!(d.front(), Some(&3));
+    /// assert_eq!(d.back(), Some(&1));
+    ///
+    /// *d.push_front() = 4;
+    /// assert_eq!(d.front(), Some(&4));
+    /// assert_eq!(d.back(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self) -> &mut T::Item {
+        assert!(T::size() > 0, "Cannot add to a zero-sized deque");
 
-    /// Copies a contiguous block of memory len long from src to dst
-    #[inline]
-    unsafe fn copy(&mut self, dst: usize, src: usize, len: usize) {
-        debug_assert!(
-            dst + len <= T::size(),
-            "cpy dst={} src={} len={} cap={}",
-            dst,
-            src,
-            len,
-            T::size()
-        );
+        // overwriting existing elements.
+        if self.len == T::size() {
+            #[cfg(feature = "overwrite-count")]
+            {
+                self.overwrites += 1;
+            }
 
-        debug_assert!(
-            src + len <= T::size(),
-            "cpy dst={} src={} len={} cap={}",
-            dst,
-            src,
-            len,
-            T::size()
-        );
+            self.head = T::wrap_sub(self.head, 1);
+            let front = self.head;
+            return unsafe { self.buffer_mut(front) };
+        }
 
-        let m = self.data.ptr_mut();
-        ptr::copy(m.add(src), m.add(dst), len);
+        self.len += 1;
+        let front = self.tail();
+        unsafe { self.buffer_mut(front) }
     }
-}
 
-impl<T> FixedVecDeque<T>
-where
-    T: Array,
-    T::Item: Clone,
-{
-    /// Modifies the `FixedVecDeque` in-place so that `len()` is equal to new_len,
-    /// either by removing excess elements from the back or by appending clones of `value`
-    /// to the back.
+    /// Prepends every element of the iterator to the `FixedVecDeque` through [`push_front`].
+    ///
+    /// Each pushed element becomes the new front, so the elements end up in the reverse order of
+    /// the iterator. If the buffer is full, elements at the back are overwritten.
     ///
     /// # Panics
     ///
-    /// Panics if `new_len` is longer than the [`capacity`] of this buffer.
+    /// Calling this function will panic if the circular buffer is zero-sized and the iterator is
+    /// not empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// let mut buf = FixedVecDeque::<[u32; 8]>::new();
-    /// *buf.push_back() = 5;
-    /// *buf.push_back() = 10;
-    /// *buf.push_back() = 15;
-    /// assert_eq!(buf, [5, 10, 15]);
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 1;
+    /// buf.extend_front([2, 3, 4]);
+    /// assert_eq!(buf, [4, 3, 2, 1]);
     ///
-    /// buf.resize(2, 0);
-    /// assert_eq!(buf, [5, 10]);
+    /// buf.extend_front([5]);
+    /// assert_eq!(buf, [5, 4, 3, 2]);
+    /// ```
+    ///
+    /// [`push_front`]: struct.FixedVecDeque.html#method.push_front
+    pub fn extend_front<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T::Item>,
+    {
+        for elt in iter {
+            *self.push_front() = elt;
+        }
+    }
+
+    /// Appends elements of the iterator to the back of the `FixedVecDeque` until it is full.
+    ///
+    /// Unlike the `Extend` implementation, which keeps pushing and overwrites the front once the
+    /// buffer is full, this keeps the existing elements and stops consuming the iterator as soon
+    /// as there's no more room. So `Extend` keeps the last elements of the iterator, while this
+    /// keeps the first.
+    ///
+    /// # Examples
     ///
-    /// buf.resize(5, 20);
-    /// assert_eq!(buf, [5, 10, 20, 20, 20]);
     /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
     ///
-    /// [`capacity`]: struct.FixedVecDeque.html#method.capacity
-    pub fn resize(&mut self, new_len: usize, value: T::Item) {
-        assert!(new_len < T::size(), "resize beyond capacity");
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 0;
+    /// buf.extend_bounded(1..10);
+    /// assert_eq!(buf, [0, 1, 2, 3]);
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 0;
+    /// buf.extend(1..10);
+    /// assert_eq!(buf, [6, 7, 8, 9]);
+    /// ```
+    pub fn extend_bounded<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T::Item>,
+    {
+        if self.is_full() {
+            return;
+        }
 
-        let len = self.len();
+        for elt in iter {
+            *self.push_back() = elt;
 
-        if new_len > len {
-            self.extend(repeat(value).take(new_len - len))
-        } else {
-            self.truncate(new_len);
+            if self.is_full() {
+                break;
+            }
         }
     }
-}
 
-impl<T> Default for FixedVecDeque<T>
-where
-    T: Array,
-    T::Item: Default,
-{
-    #[inline]
-    fn default() -> Self {
-        Self::new()
-    }
-}
+    /// Removes the first element and returns it, or `None` if the `FixedVecDeque` is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
+    /// *d.push_back() = 1;
+    /// *d.push_back() = 2;
+    ///
+    /// assert_eq!(d.pop_front(), Some(&mut 1));
+    /// assert_eq!(d.pop_front(), Some(&mut 2));
+    /// assert_eq!(d.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<&mut T::Item> {
+        if self.is_empty() {
+            return None;
+        }
 
-impl<A> hash::Hash for FixedVecDeque<A>
-where
-    A: Array,
-    A::Item: hash::Hash,
-{
-    fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.len().hash(state);
-        let (a, b) = self.as_slices();
-        hash::Hash::hash_slice(a, state);
-        hash::Hash::hash_slice(b, state);
+        let tail = self.tail();
+        self.len -= 1;
+        unsafe { Some(self.buffer_mut(tail)) }
     }
-}
 
-impl<T> Index<usize> for FixedVecDeque<T>
-where
-    T: Array,
-{
-    type Output = T::Item;
+    /// Removes up to `n` elements from the front of the `FixedVecDeque`, returning the number of
+    /// elements which were removed.
+    ///
+    /// Like [`pop_front`], the removed elements are left in place in the underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(buf.pop_front_n(3), 3);
+    /// assert_eq!(buf, [4]);
+    ///
+    /// assert_eq!(buf.pop_front_n(3), 1);
+    /// assert!(buf.is_empty());
+    /// ```
+    ///
+    /// [`pop_front`]: struct.FixedVecDeque.html#method.pop_front
+    pub fn pop_front_n(&mut self, n: usize) -> usize {
+        let n = cmp::min(n, self.len);
+        self.len -= n;
+        n
+    }
 
-    fn index(&self, index: usize) -> &T::Item {
-        self.get(index).expect("Out of bounds access")
+    /// Removes the first element and returns a copy of it, or `None` if the `FixedVecDeque` is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
+    /// d.extend([1, 2]);
+    ///
+    /// assert_eq!(d.pop_front_copied(), Some(1));
+    /// assert_eq!(d.pop_front_copied(), Some(2));
+    /// assert_eq!(d.pop_front_copied(), None);
+    /// ```
+    #[inline]
+    pub fn pop_front_copied(&mut self) -> Option<T::Item>
+    where
+        T::Item: Copy,
+    {
+        self.pop_front().copied()
     }
-}
 
-impl<T> IndexMut<usize> for FixedVecDeque<T>
-where
-    T: Array,
-{
-    fn index_mut(&mut self, index: usize) -> &mut T::Item {
-        self.get_mut(index).expect("Out of bounds access")
+    /// Removes the first element and returns a clone of it, or `None` if the `FixedVecDeque` is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[String; 2]>::new();
+    /// d.push_back().push_str("a");
+    /// d.push_back().push_str("b");
+    ///
+    /// assert_eq!(d.pop_front_cloned().as_deref(), Some("a"));
+    /// assert_eq!(d.pop_front_cloned().as_deref(), Some("b"));
+    /// assert_eq!(d.pop_front_cloned(), None);
+    /// ```
+    #[inline]
+    pub fn pop_front_cloned(&mut self) -> Option<T::Item>
+    where
+        T::Item: Clone,
+    {
+        self.pop_front().cloned()
     }
-}
 
-/// An iterator over the elements of a `FixedVecDeque`.
-///
-/// This `struct` is created by the [`iter`] method on [`FixedVecDeque`]. See its
-/// documentation for more.
-///
-/// [`iter`]: struct.FixedVecDeque.html#method.iter
-/// [`FixedVecDeque`]: struct.FixedVecDeque.html
-pub struct Iter<'a, T: 'a>
-where
-    T: Array,
-{
-    data: *const T::Item,
-    head: usize,
-    len: usize,
-    marker: marker::PhantomData<&'a ()>,
-}
+    /// Removes the first element and returns it if it satisfies the given predicate, or `None` if
+    /// it doesn't or if the `FixedVecDeque` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[u32; 4]>::new();
+    /// d.extend([1, 2, 3]);
+    ///
+    /// assert_eq!(d.pop_front_if(|&x| x < 2), Some(&mut 1));
+    /// assert_eq!(d.pop_front_if(|&x| x < 2), None);
+    /// assert_eq!(d, [2, 3]);
+    /// ```
+    pub fn pop_front_if<F>(&mut self, pred: F) -> Option<&mut T::Item>
+    where
+        F: FnOnce(&T::Item) -> bool,
+    {
+        if !pred(self.front()?) {
+            return None;
+        }
 
-impl<'a, T: 'a> Iterator for Iter<'a, T>
-where
-    T: Array,
-{
-    type Item = &'a T::Item;
+        self.pop_front()
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
+    /// Appends an element to the back of the `FixedVecDeque` by returning a mutable reference that
+    /// can be modified to it.
+    ///
+    /// Note: this might potentially remove elements from the head, unless they have been read.
+    ///
+    /// # Panics
+    ///
+    /// Calling this function will panic if the circular buffer is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 2]>::new();
+    /// assert_eq!(buf.back(), None);
+    /// assert_eq!(buf.front(), None);
+    ///
+    /// *buf.push_back() = 1;
+    ///
+    /// assert_eq!(buf.front(), Some(&1));
+    /// assert_eq!(buf.back(), Some(&1));
+    ///
+    /// *buf.push_back() = 2;
+    ///
+    /// assert_eq!(buf.front(), Some(&1));
+    /// assert_eq!(buf.back(), Some(&2));
+    ///
+    /// *buf.push_back() = 3;
+    ///
+    /// assert_eq!(buf.front(), Some(&2));
+    /// assert_eq!(buf.back(), Some(&3));
+    /// ```
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 1]>::new();
+    /// assert_eq!(buf.back(), None);
+    /// assert_eq!(buf.front(), None);
+    ///
+    /// *buf.push_back() = 1;
+    ///
+    /// assert_eq!(buf.front(), Some(&1));
+    /// assert_eq!(buf.back(), Some(&1));
+    ///
+    /// *buf.push_back() = 2;
+    ///
+    /// assert_eq!(buf.front(), Some(&2));
+    /// assert_eq!(buf.back(), Some(&2));
+    ///
+    /// buf.pop_back();
+    ///
+    /// assert!(buf.is_empty());
+    /// assert_eq!(buf.back(), None);
+    /// assert_eq!(buf.front(), None);
+    /// ```
+    pub fn push_back(&mut self) -> &mut T::Item {
+        assert!(T::size() > 0, "Cannot add to a zero-sized deque");
+
+        let head = self.head;
+        self.head = T::wrap_add(self.head, 1);
+
+        if self.len < T::size() {
+            self.len += 1;
+        } else {
+            #[cfg(feature = "overwrite-count")]
+            {
+                self.overwrites += 1;
+            }
+        }
+
+        unsafe { self.buffer_mut(head) }
+    }
+
+    /// Adds an element to the back of the `FixedVecDeque` like [`push_back`], calling
+    /// `on_evict` with the front element first if it's about to be overwritten.
+    ///
+    /// `on_evict` is only called if the `FixedVecDeque` is full. The evicted element is stored in
+    /// the slot which is returned, so anything that should be kept from it must be taken out in
+    /// the callback.
+    ///
+    /// # Panics
+    ///
+    /// Calling this function will panic if the circular buffer is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 2]>::new();
+    /// let mut evicted = Vec::new();
+    ///
+    /// for v in 1..=4 {
+    ///     *buf.push_back_with_evict(|e| evicted.push(*e)) = v;
+    /// }
+    ///
+    /// assert_eq!(buf, [3, 4]);
+    /// assert_eq!(evicted, [1, 2]);
+    /// ```
+    ///
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    pub fn push_back_with_evict<F>(&mut self, on_evict: F) -> &mut T::Item
+    where
+        F: FnOnce(&mut T::Item),
+    {
+        if self.is_full() {
+            if let Some(front) = self.front_mut() {
+                on_evict(front);
+            }
+        }
+
+        self.push_back()
+    }
+
+    /// Returns the element which the next call to [`push_back`] will overwrite, or `None` if
+    /// the `FixedVecDeque` is not full.
+    ///
+    /// When full, this is the same as [`front`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 2]>::new();
+    /// *buf.push_back() = 1;
+    /// assert_eq!(buf.next_overwrite(), None);
+    ///
+    /// *buf.push_back() = 2;
+    /// assert_eq!(buf.next_overwrite(), Some(&1));
+    ///
+    /// *buf.push_back() = 3;
+    /// assert_eq!(buf.next_overwrite(), Some(&2));
+    /// ```
+    ///
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    /// [`front`]: struct.FixedVecDeque.html#method.front
+    pub fn next_overwrite(&self) -> Option<&T::Item> {
+        if !self.is_full() {
+            return None;
+        }
+
+        self.front()
+    }
+
+    /// Adds `n` elements to the back of the `FixedVecDeque` at once, returning mutable slices to
+    /// the added elements in front-to-back order so that they can be written in bulk.
+    ///
+    /// This behaves like calling [`push_back`] `n` times. As with it the returned elements keep
+    /// whatever value was previously stored in their slots, and if the buffer is full elements at
+    /// the front are overwritten. If `n` is greater than the capacity, only the last `capacity()`
+    /// added elements survive, so only those are returned.
+    ///
+    /// # Panics
+    ///
+    /// Calling this function will panic if the circular buffer is zero-sized and `n` is not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 1;
+    ///
+    /// let (a, b) = buf.push_back_n(2);
+    /// assert_eq!(a.len() + b.len(), 2);
+    ///
+    /// for (i, v) in a.iter_mut().chain(b).enumerate() {
+    ///     *v = i as u32 + 2;
+    /// }
+    ///
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// ```
+    ///
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    pub fn push_back_n(&mut self, n: usize) -> (&mut [T::Item], &mut [T::Item]) {
+        assert!(n == 0 || T::size() > 0, "Cannot add to a zero-sized deque");
+
+        #[cfg(feature = "overwrite-count")]
+        {
+            self.overwrites += n.saturating_sub(T::size() - self.len) as u64;
+        }
+
+        self.head = T::wrap_add(self.head, n % cmp::max(T::size(), 1));
+        self.len = cmp::min(self.len + cmp::min(n, T::size()), T::size());
+
+        let end = self.len;
+        let start = end - cmp::min(n, T::size());
+
+        let (a, b) = self.as_mut_slices();
+        let split = a.len();
+
+        (
+            &mut a[cmp::min(start, split)..cmp::min(end, split)],
+            &mut b[start.saturating_sub(split)..end.saturating_sub(split)],
+        )
+    }
+
+    /// Gets an entry for the back of the `FixedVecDeque`, which can be used to decide whether to
+    /// append an element or not.
+    ///
+    /// Nothing is appended unless the entry is committed through [`BackEntry::or_default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 1;
+    ///
+    /// let entry = buf.back_entry();
+    ///
+    /// if entry.back() == Some(&1) {
+    ///     *entry.or_default() += 2;
+    /// } else {
+    ///     entry.discard();
+    /// }
+    ///
+    /// assert_eq!(buf, [1, 2]);
+    /// ```
+    ///
+    /// [`BackEntry::or_default`]: struct.BackEntry.html#method.or_default
+    pub fn back_entry(&mut self) -> BackEntry<'_, T> {
+        BackEntry { deq: self }
+    }
+
+    /// Removes the last element from the `FixedVecDeque` and returns a reference to it, or `None`
+    /// if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 2]>::new();
+    /// assert_eq!(buf.pop_back(), None);
+    /// *buf.push_back() = 1;
+    /// *buf.push_back() = 3;
+    /// assert_eq!(buf.pop_back(), Some(&mut 3));
+    /// ```
+    pub fn pop_back(&mut self) -> Option<&mut T::Item> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.head = T::wrap_sub(self.head, 1);
+        self.len -= 1;
+        let head = self.head;
+        unsafe { Some(self.buffer_mut(head)) }
+    }
+
+    /// Removes up to `n` elements from the back of the `FixedVecDeque`, returning the number of
+    /// elements which were removed.
+    ///
+    /// Like [`pop_back`], the removed elements are left in place in the underlying buffer. This
+    /// is the same as truncating the `FixedVecDeque` to `len() - n` elements, saturating at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(buf.pop_back_n(3), 3);
+    /// assert_eq!(buf, [1]);
+    ///
+    /// assert_eq!(buf.pop_back_n(3), 1);
+    /// assert!(buf.is_empty());
+    /// ```
+    ///
+    /// [`pop_back`]: struct.FixedVecDeque.html#method.pop_back
+    pub fn pop_back_n(&mut self, n: usize) -> usize {
+        let n = cmp::min(n, self.len);
+        self.head = T::wrap_sub(self.head, n);
+        self.len -= n;
+        n
+    }
+
+    /// Removes the last element and returns a copy of it, or `None` if the `FixedVecDeque` is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[u32; 2]>::new();
+    /// d.extend([1, 2]);
+    ///
+    /// assert_eq!(d.pop_back_copied(), Some(2));
+    /// assert_eq!(d.pop_back_copied(), Some(1));
+    /// assert_eq!(d.pop_back_copied(), None);
+    /// ```
+    #[inline]
+    pub fn pop_back_copied(&mut self) -> Option<T::Item>
+    where
+        T::Item: Copy,
+    {
+        self.pop_back().copied()
+    }
+
+    /// Removes the last element and returns a clone of it, or `None` if the `FixedVecDeque` is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[String; 2]>::new();
+    /// d.push_back().push_str("a");
+    /// d.push_back().push_str("b");
+    ///
+    /// assert_eq!(d.pop_back_cloned().as_deref(), Some("b"));
+    /// assert_eq!(d.pop_back_cloned().as_deref(), Some("a"));
+    /// assert_eq!(d.pop_back_cloned(), None);
+    /// ```
+    #[inline]
+    pub fn pop_back_cloned(&mut self) -> Option<T::Item>
+    where
+        T::Item: Clone,
+    {
+        self.pop_back().cloned()
+    }
+
+    /// Removes the last element and returns it if it satisfies the given predicate, or `None` if
+    /// it doesn't or if the `FixedVecDeque` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut d = FixedVecDeque::<[u32; 4]>::new();
+    /// d.extend([1, 2, 3]);
+    ///
+    /// assert_eq!(d.pop_back_if(|&x| x > 2), Some(&mut 3));
+    /// assert_eq!(d.pop_back_if(|&x| x > 2), None);
+    /// assert_eq!(d, [1, 2]);
+    /// ```
+    pub fn pop_back_if<F>(&mut self, pred: F) -> Option<&mut T::Item>
+    where
+        F: FnOnce(&T::Item) -> bool,
+    {
+        if !pred(self.back()?) {
             return None;
         }
 
-        let tail = T::wrap_sub(self.head, self.len);
-        self.len -= 1;
-        Some(unsafe { &*self.data.add(tail) })
+        self.pop_back()
+    }
+
+    /// Removes an element from anywhere in the `FixedVecDeque` and returns a mutable reference to
+    /// it, replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.swap_remove_back(0), None);
+    /// *buf.push_back() = 1;
+    /// *buf.push_back() = 2;
+    /// *buf.push_back() = 3;
+    /// assert_eq!(buf, [1, 2, 3]);
+    ///
+    /// assert_eq!(buf.swap_remove_back(0), Some(&mut 1));
+    /// assert_eq!(buf, [3, 2]);
+    /// ```
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<&mut T::Item> {
+        let length = self.len();
+        if length > 0 && index < length - 1 {
+            self.swap(index, length - 1);
+        } else if index >= length {
+            return None;
+        }
+        self.pop_back()
+    }
+
+    /// Removes an element from anywhere in the `FixedVecDeque` and returns a reference to it,
+    /// replacing it with the first element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.swap_remove_front(0), None);
+    /// *buf.push_back() = 1;
+    /// *buf.push_back() = 2;
+    /// *buf.push_back() = 3;
+    /// assert_eq!(buf, [1, 2, 3]);
+    ///
+    /// assert_eq!(buf.swap_remove_front(2), Some(&mut 3));
+    /// assert_eq!(buf, [2, 1]);
+    /// ```
+    pub fn swap_remove_front(&mut self, index: usize) -> Option<&mut T::Item> {
+        let length = self.len();
+        if length > 0 && index < length && index != 0 {
+            self.swap(index, 0);
+        } else if index >= length {
+            return None;
+        }
+        self.pop_front()
+    }
+
+    /// Removes and returns the element at `index` from the `VecDeque`.
+    /// Whichever end is closer to the removal point will be moved to make
+    /// room, and all the affected elements will be moved to new positions.
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 1;
+    /// *buf.push_back() = 2;
+    /// *buf.push_back() = 3;
+    /// assert_eq!(buf, [1, 2, 3]);
+    ///
+    /// assert_eq!(buf.remove(1), Some(&mut 2));
+    /// assert_eq!(buf, [1, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<&mut T::Item>
+    where
+        T::Item: fmt::Debug,
+    {
+        // if empty, nothing to do.
+        if T::size() == 0 || index >= self.len {
+            return None;
+        }
+
+        // There are three main cases:
+        //  Elements are contiguous
+        //  Elements are discontiguous and the removal is in the tail section
+        //  Elements are discontiguous and the removal is in the head section
+        //      - special case when elements are technically contiguous,
+        //        but self.head = 0
+        //
+        // For each of those there are two more cases:
+        //  Insert is closer to tail
+        //  Insert is closer to head
+        //
+        // Key: H - self.head
+        //      T - self.tail
+        //      o - Valid element
+        //      x - Element marked for removal
+        //      R - Indicates element that is being removed
+        //      M - Indicates element was moved
+
+        let idx = self.ptr_index(index);
+        let head = self.head;
+        let tail = self.tail();
+
+        let tmp = unsafe { self.buffer_read(idx) };
+
+        let distance_to_tail = index;
+        let distance_to_head = self.len() - index;
+
+        // NB: this is stricter than `is_contiguous`, since the cases below require the elements to
+        // end strictly before the head in the underlying buffer.
+        let contiguous = self.len != T::size() && tail <= head;
+
+        let idx = match (
+            contiguous,
+            distance_to_tail <= distance_to_head,
+            idx >= tail,
+        ) {
+            (true, true, _) => {
+                unsafe {
+                    // contiguous, remove closer to tail:
+                    //
+                    //             T   R         H
+                    //      [. . . o o x o o o o . . . . . .]
+                    //
+                    //               T           H
+                    //      [. . . . o o o o o o . . . . . .]
+                    //               M M
+
+                    self.copy(tail + 1, tail, index);
+                    tail
+                }
+            }
+            (true, false, _) => {
+                unsafe {
+                    // contiguous, remove closer to head:
+                    //
+                    //             T       R     H
+                    //      [. . . o o o o x o o . . . . . .]
+                    //
+                    //             T           H
+                    //      [. . . o o o o o o . . . . . . .]
+                    //                     M M
+
+                    self.copy(idx, idx + 1, head - idx - 1);
+                    self.head -= 1;
+                    head
+                }
+            }
+            (false, true, true) => {
+                unsafe {
+                    // discontiguous, remove closer to tail, tail section:
+                    //
+                    //                   H         T   R
+                    //      [o o o o o o . . . . . o o x o o]
+                    //
+                    //                   H           T
+                    //      [o o o o o o . . . . . . o o o o]
+                    //                               M M
+
+                    self.copy(tail + 1, tail, index);
+                    tail
+                }
+            }
+            (false, false, false) => {
+                unsafe {
+                    // discontiguous, remove closer to head, head section:
+                    //
+                    //               R     H           T
+                    //      [o o o o x o o . . . . . . o o o]
+                    //
+                    //                   H             T
+                    //      [o o o o o o . . . . . . . o o o]
+                    //               M M
+
+                    self.copy(idx, idx + 1, head - idx - 1);
+                    self.head -= 1;
+                    head
+                }
+            }
+            (false, false, true) => {
+                unsafe {
+                    // discontiguous, remove closer to head, tail section:
+                    //
+                    //             H           T         R
+                    //      [o o o . . . . . . o o o o o x o]
+                    //
+                    //           H             T
+                    //      [o o . . . . . . . o o o o o o o]
+                    //       M M                         M M
+                    //
+                    // or quasi-discontiguous, remove next to head, tail section:
+                    //
+                    //       H                 T         R
+                    //      [. . . . . . . . . o o o o o x o]
+                    //
+                    //                         T           H
+                    //      [. . . . . . . . . o o o o o o .]
+                    //                                   M
+
+                    // draw in elements in the tail section
+                    self.copy(idx, idx + 1, T::size() - idx - 1);
+
+                    // Prevents underflow.
+                    if head != 0 {
+                        // copy first element into empty spot
+                        self.copy(T::size() - 1, 0, 1);
+
+                        // move elements in the head section backwards
+                        self.copy(0, 1, head - 1);
+                    }
+
+                    self.head = T::wrap_sub(self.head, 1);
+                    head
+                }
+            }
+            (false, true, false) => {
+                unsafe {
+                    // discontiguous, remove closer to tail, head section:
+                    //
+                    //           R               H     T
+                    //      [o o x o o o o o o o . . . o o o]
+                    //
+                    //                           H       T
+                    //      [o o o o o o o o o o . . . . o o]
+                    //       M M M                       M M
+
+                    // draw in elements up to idx
+                    self.copy(1, 0, idx);
+
+                    // copy last element into empty spot
+                    self.copy(0, T::size() - 1, 1);
+
+                    // move elements from tail to end forward, excluding the last one
+                    self.copy(tail + 1, tail, T::size() - tail - 1);
+
+                    tail
+                }
+            }
+        };
+
+        self.len -= 1;
+
+        unsafe {
+            // write temporary into shifted location since we need a stable memory location for it!
+            self.buffer_write(idx, tmp);
+            Some(self.buffer_mut(idx))
+        }
+    }
+
+    /// Inserts `value` into a `FixedVecDeque` which is sorted in ascending order, keeping it
+    /// sorted, and returns a reference to the inserted element.
+    ///
+    /// The insertion point is found through a binary search and is placed after any elements
+    /// that compare equal to `value`. If the deque is not sorted the insertion point is
+    /// unspecified.
+    ///
+    /// If the `FixedVecDeque` is full, the front element (which is the smallest) is evicted if
+    /// it is smaller than `value`. Otherwise `value` is the smallest of the candidates, so it is
+    /// dropped, the deque is left untouched and `None` is returned. This keeps the `capacity()`
+    /// largest elements seen.
+    ///
+    /// # Panics
+    ///
+    /// Calling this function will panic if the circular buffer is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    ///
+    /// for v in [5, 1, 4, 2] {
+    ///     buf.insert_sorted(v);
+    /// }
+    ///
+    /// assert_eq!(buf, [1, 2, 4, 5]);
+    ///
+    /// // The buffer is full, so the smallest element is evicted.
+    /// assert_eq!(buf.insert_sorted(3), Some(&mut 3));
+    /// assert_eq!(buf, [2, 3, 4, 5]);
+    ///
+    /// // Values which aren't larger than the smallest element are dropped.
+    /// assert_eq!(buf.insert_sorted(1), None);
+    /// assert_eq!(buf, [2, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, value: T::Item) -> Option<&mut T::Item>
+    where
+        T::Item: Ord,
+    {
+        assert!(T::size() > 0, "Cannot add to a zero-sized deque");
+
+        if self.is_full() {
+            match self.front() {
+                Some(front) if *front < value => {
+                    self.pop_front();
+
+                    #[cfg(feature = "overwrite-count")]
+                    {
+                        self.overwrites += 1;
+                    }
+                }
+                _ => return None,
+            }
+        }
+
+        let index = {
+            let (front, back) = self.as_slices();
+
+            match back.first() {
+                Some(first) if *first <= value => {
+                    front.len() + back.partition_point(|e| *e <= value)
+                }
+                _ => front.partition_point(|e| *e <= value),
+            }
+        };
+
+        self.push_back();
+
+        let size = T::size();
+        let back = T::wrap_sub(self.head, 1);
+        let idx = self.ptr_index(index);
+
+        unsafe {
+            // the slot made available at the back holds a stale element which is dropped once
+            // the new value is in place.
+            let stale = self.buffer_read(back);
+
+            if idx <= back {
+                //           I       B
+                //  [. . o o x o o o . . .]
+                self.copy(idx + 1, idx, back - idx);
+            } else {
+                //       B         I
+                //  [o o . . . o o x o o o]
+                self.copy(1, 0, back);
+                self.copy(0, size - 1, 1);
+                self.copy(idx + 1, idx, size - idx - 1);
+            }
+
+            self.buffer_write(idx, value);
+            drop(stale);
+            Some(self.buffer_mut(idx))
+        }
+    }
+
+    /// Merges the elements of `other` into this `FixedVecDeque`, where both are sorted in
+    /// ascending order, keeping this deque sorted.
+    ///
+    /// Elements from `other` are placed after any elements in `self` which compare equal to them.
+    ///
+    /// If the merged elements don't fit, the largest elements are dropped so that the
+    /// `capacity()` smallest elements are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut a = FixedVecDeque::<[u32; 5]>::new();
+    /// a.extend([1, 4, 6]);
+    ///
+    /// let mut b = FixedVecDeque::<[u32; 5]>::new();
+    /// b.extend([2, 3, 5, 7]);
+    ///
+    /// a.merge_sorted(&b);
+    /// assert_eq!(a, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn merge_sorted(&mut self, other: &FixedVecDeque<T>)
+    where
+        T::Item: Ord + Clone,
+    {
+        for v in other.iter() {
+            if self.is_full() {
+                match self.back() {
+                    Some(back) if v < back => {
+                        self.pop_back();
+                    }
+                    // every remaining element is at least as large as the largest one we hold.
+                    _ => break,
+                }
+            }
+
+            self.insert_sorted(v.clone());
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns false.
+    /// This method operates in place and preserves the order of the retained
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[usize; 8]>::new();
+    /// buf.extend(1..5);
+    /// buf.retain(|&x| x % 2 == 0);
+    /// assert_eq!(buf, [2, 4]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T::Item) -> bool,
+    {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(&mut e)` returns false.
+    /// This method operates in place and preserves the order of the retained
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[usize; 8]>::new();
+    /// buf.extend(1..5);
+    /// buf.retain_mut(|x| {
+    ///     *x *= 3;
+    ///     *x % 2 == 0
+    /// });
+    /// assert_eq!(buf, [6, 12]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T::Item) -> bool,
+    {
+        let len = self.len();
+        let mut del = 0;
+
+        for i in 0..len {
+            let off = self.ptr_index(i);
+
+            if !f(unsafe { self.buffer_mut(off) }) {
+                del += 1;
+            } else if del > 0 {
+                // NB: every slot in `i - del..i` holds a deleted element, so swapping moves the
+                // retained element down past all of them and preserves the relative order of the
+                // retained elements. Swapping rather than copying also makes sure that no element is
+                // duplicated.
+                self.swap(i - del, i);
+            }
+        }
+
+        if del > 0 {
+            self.truncate(len - del);
+        }
+    }
+
+    /// Returns a front-to-back iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 5;
+    /// *buf.push_back() = 3;
+    /// *buf.push_back() = 4;
+    ///
+    /// let b: &[_] = &[&5, &3, &4];
+    /// let c: Vec<&u32> = buf.iter().collect();
+    /// assert_eq!(&c[..], b);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            data: self.data.ptr(),
+            head: self.head,
+            len: self.len,
+            marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a front-to-back iterator which yields each element together with its index.
+    ///
+    /// Element at index 0 is the front of the queue. The iterator is double-ended, so the
+    /// elements can be cheaply enumerated in reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([5, 3, 4]);
+    ///
+    /// let forward = buf.indexed().collect::<Vec<_>>();
+    /// assert_eq!(forward, [(0, &5), (1, &3), (2, &4)]);
+    ///
+    /// let backward = buf.indexed().rev().collect::<Vec<_>>();
+    /// assert_eq!(backward, [(2, &4), (1, &3), (0, &5)]);
+    /// ```
+    pub fn indexed(&self) -> iter::Enumerate<Iter<'_, T>> {
+        self.iter().enumerate()
+    }
+
+    /// Returns a front-to-back iterator over the elements, together with the physical offset in
+    /// the underlying buffer which each element is stored at.
+    ///
+    /// Physical offsets wrap, so once the end of the underlying buffer is reached the offsets
+    /// continue from `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
+    ///
+    /// let slots = buf.iter_physical().collect::<Vec<_>>();
+    /// assert_eq!(slots, vec![(1, &2), (2, &3), (3, &4), (0, &5)]);
+    /// ```
+    pub fn iter_physical(&self) -> impl Iterator<Item = (usize, &T::Item)> {
+        let tail = self.tail();
+
+        self.iter()
+            .enumerate()
+            .map(move |(i, v)| (T::wrap_add(tail, i), v))
+    }
+
+    /// Returns a front-to-back iterator over the elements, together with the age of each
+    /// element.
+    ///
+    /// The age is the number of elements which were pushed to the back after it, so the back
+    /// element has an age of `0` and the front element has an age of `len() - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 3]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    ///
+    /// let aged = buf.iter_aged().collect::<Vec<_>>();
+    /// assert_eq!(aged, vec![(2, &2), (1, &3), (0, &4)]);
+    /// ```
+    pub fn iter_aged(&self) -> impl Iterator<Item = (usize, &T::Item)> {
+        let len = self.len;
+
+        self.iter().enumerate().map(move |(i, v)| (len - 1 - i, v))
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 5;
+    /// *buf.push_back() = 3;
+    /// *buf.push_back() = 4;
+    /// for num in buf.iter_mut() {
+    ///     *num = *num - 2;
+    /// }
+    /// let b: &[_] = &[&mut 3, &mut 1, &mut 2];
+    /// assert_eq!(&buf.iter_mut().collect::<Vec<&mut u32>>()[..], b);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            data: self.data.ptr_mut(),
+            head: self.head,
+            len: self.len,
+            marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references, together with the
+    /// logical index of each element.
+    ///
+    /// This is like [`indexed`], but for mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([10, 20, 30, 40, 50]);
+    ///
+    /// for (i, num) in buf.iter_mut_indexed() {
+    ///     if i % 2 == 0 {
+    ///         *num /= 10;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(buf, [2, 30, 4, 50]);
+    /// ```
+    ///
+    /// [`indexed`]: struct.FixedVecDeque.html#method.indexed
+    pub fn iter_mut_indexed(&mut self) -> iter::Enumerate<IterMut<'_, T>> {
+        self.iter_mut().enumerate()
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`. The windows overlap. If
+    /// the `FixedVecDeque` is shorter than `size`, the iterator returns no values.
+    ///
+    /// Since the elements in a window might wrap around the underlying buffer, each window is
+    /// provided as an [`Iter`] over its elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// *buf.push_front() = 0;
+    ///
+    /// let mut it = buf.windows(3);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert!(it.next().is_none());
+    /// ```
+    ///
+    /// [`Iter`]: struct.Iter.html
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+
+        Windows {
+            iter: self.iter(),
+            size,
+        }
+    }
+
+    /// Returns a front-to-back iterator over all pairs of adjacent elements in the
+    /// `FixedVecDeque`.
+    ///
+    /// This is like [`windows`] with a size of 2, but yields each window as a tuple. If the
+    /// `FixedVecDeque` has fewer than two elements, the iterator yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 4, 7, 11]);
+    ///
+    /// let deltas = buf.pairwise().map(|(a, b)| b - a).collect::<Vec<_>>();
+    /// assert_eq!(deltas, [2, 3, 4]);
+    /// ```
+    ///
+    /// [`windows`]: struct.FixedVecDeque.html#method.windows
+    pub fn pairwise(&self) -> impl Iterator<Item = (&T::Item, &T::Item)> {
+        let mut next = self.iter();
+        next.next();
+        self.iter().zip(next)
+    }
+
+    /// Returns an iterator over `size` elements of the `FixedVecDeque` at a time, starting at the
+    /// front. The chunks do not overlap. If `size` does not divide the length of the
+    /// `FixedVecDeque`, then the last chunk will not have length `size`.
+    ///
+    /// Since the elements in a chunk might wrap around the underlying buffer, each chunk is
+    /// provided as an [`Iter`] over its elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 8]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
+    ///
+    /// let mut it = buf.chunks(2);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [3, 4]);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [5]);
+    /// assert!(it.next().is_none());
+    /// ```
+    ///
+    /// [`Iter`]: struct.Iter.html
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        Chunks {
+            iter: self.iter(),
+            size,
+        }
+    }
+
+    /// Returns an iterator over `size` elements of the `FixedVecDeque` at a time, starting at the
+    /// back. The chunks do not overlap. If `size` does not divide the length of the
+    /// `FixedVecDeque`, then the last chunk, which contains the front, will not have length
+    /// `size`.
+    ///
+    /// The elements within each chunk are in front-to-back order, and like with [`chunks`] each
+    /// chunk is provided as an [`Iter`] over its elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 8]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
+    ///
+    /// let mut it = buf.rchunks(2);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [4, 5]);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [2, 3]);
+    /// assert_eq!(it.next().unwrap().copied().collect::<Vec<_>>(), [1]);
+    /// assert!(it.next().is_none());
+    /// ```
+    ///
+    /// [`chunks`]: struct.FixedVecDeque.html#method.chunks
+    /// [`Iter`]: struct.Iter.html
+    pub fn rchunks(&self, size: usize) -> RChunks<'_, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        RChunks {
+            iter: self.iter(),
+            size,
+        }
+    }
+
+    /// Applies `f` to every element of the `FixedVecDeque` in place, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// *buf.push_front() = 4;
+    ///
+    /// buf.apply(|x| *x *= 2);
+    /// assert_eq!(buf, [8, 2, 4, 6]);
+    /// ```
+    pub fn apply<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T::Item),
+    {
+        let (a, b) = self.as_mut_slices();
+        a.iter_mut().for_each(&mut f);
+        b.iter_mut().for_each(f);
+    }
+
+    /// Creates an iterator that covers the specified range in the `FixedVecDeque`.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// let range = buf.range(2..).copied().collect::<Vec<_>>();
+    /// assert_eq!(range, [3]);
+    ///
+    /// let all = buf.range(..).copied().collect::<Vec<_>>();
+    /// assert_eq!(all, [1, 2, 3]);
+    /// ```
+    pub fn range<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_bounds(range);
+
+        Iter {
+            data: self.data.ptr(),
+            head: self.ptr_index(end),
+            len: end - start,
+            marker: marker::PhantomData,
+        }
+    }
+
+    /// Creates an iterator that covers the specified mutable range in the `FixedVecDeque`.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// for v in buf.range_mut(2..) {
+    ///     *v *= 2;
+    /// }
+    ///
+    /// assert_eq!(buf, [1, 2, 6]);
+    /// ```
+    pub fn range_mut<R>(&mut self, range: R) -> IterMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_bounds(range);
+
+        IterMut {
+            head: self.ptr_index(end),
+            len: end - start,
+            data: self.data.ptr_mut(),
+            marker: marker::PhantomData,
+        }
+    }
+
+    /// Clears the `FixedVecDeque`.
+    ///
+    /// The stored values will _not_ be deleted. With the `overwrite-count` feature enabled, this
+    /// also resets the [`overwrite_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut v = FixedVecDeque::<[u32; 1]>::new();
+    /// *v.push_back() = 1;
+    /// v.clear();
+    /// assert!(v.is_empty());
+    /// ```
+    ///
+    /// [`overwrite_count`]: struct.FixedVecDeque.html#method.overwrite_count
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+
+        #[cfg(feature = "overwrite-count")]
+        {
+            self.overwrites = 0;
+        }
+    }
+
+    /// Returns `true` if the `FixedVecDeque` contains an element equal to the
+    /// given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut vector = FixedVecDeque::<[u32; 4]>::new();
+    ///
+    /// *vector.push_back() = 0;
+    /// *vector.push_back() = 1;
+    ///
+    /// assert_eq!(vector.contains(&1), true);
+    /// assert_eq!(vector.contains(&10), false);
+    /// ```
+    pub fn contains(&self, x: &T::Item) -> bool
+    where
+        T::Item: PartialEq<T::Item>,
+    {
+        let (a, b) = self.as_slices();
+        a.contains(x) || b.contains(x)
+    }
+
+    /// Returns the index of the first element in the `FixedVecDeque` which is equal to the given
+    /// value, or `None` if there is no such element.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut vector = FixedVecDeque::<[u32; 4]>::new();
+    /// vector.extend([3, 1, 2, 1]);
+    ///
+    /// assert_eq!(vector.position(&1), Some(1));
+    /// assert_eq!(vector.position(&10), None);
+    /// ```
+    pub fn position(&self, x: &T::Item) -> Option<usize>
+    where
+        T::Item: PartialEq<T::Item>,
+    {
+        let (a, b) = self.as_slices();
+
+        if let Some(index) = a.iter().position(|e| e == x) {
+            return Some(index);
+        }
+
+        b.iter().position(|e| e == x).map(|index| a.len() + index)
+    }
+
+    /// Returns the index of the last element in the `FixedVecDeque` which matches the given
+    /// predicate, or `None` if there is no such element.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut vector = FixedVecDeque::<[u32; 4]>::new();
+    /// vector.extend([3, 1, 2, 1]);
+    ///
+    /// assert_eq!(vector.rposition(|&x| x == 1), Some(3));
+    /// assert_eq!(vector.rposition(|&x| x > 2), Some(0));
+    /// assert_eq!(vector.rposition(|&x| x == 10), None);
+    /// ```
+    pub fn rposition<P>(&self, mut pred: P) -> Option<usize>
+    where
+        P: FnMut(&T::Item) -> bool,
+    {
+        let (a, b) = self.as_slices();
+
+        if let Some(index) = b.iter().rposition(&mut pred) {
+            return Some(a.len() + index);
+        }
+
+        a.iter().rposition(pred)
+    }
+
+    /// Returns a reference to the last element in the `FixedVecDeque` which matches the given
+    /// predicate, or `None` if there is no such element.
+    ///
+    /// Elements are visited from the back, so this stops at the most recently pushed match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut samples = FixedVecDeque::<[(u32, &str); 4]>::new();
+    /// samples.extend([(1, "ok"), (2, "err"), (3, "ok"), (4, "err"), (5, "ok")]);
+    ///
+    /// assert_eq!(samples.rfind(|s| s.1 == "err"), Some(&(4, "err")));
+    /// assert_eq!(samples.rfind(|s| s.0 < 2), None);
+    /// ```
+    pub fn rfind<P>(&self, mut pred: P) -> Option<&T::Item>
+    where
+        P: FnMut(&T::Item) -> bool,
+    {
+        let mut iter = self.iter();
+
+        while let Some(v) = iter.next_back() {
+            if pred(v) {
+                return Some(v);
+            }
+        }
+
+        None
+    }
+
+    /// Returns `true` if this `FixedVecDeque` contains the same elements as `other`, regardless
+    /// of their order.
+    ///
+    /// Elements are compared as multisets, so each element has to occur the same number of times
+    /// in both. This collects and sorts references to the elements of both deques.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut a = FixedVecDeque::<[u32; 4]>::new();
+    /// a.extend([1, 2, 2, 3]);
+    ///
+    /// let mut b = FixedVecDeque::<[u32; 4]>::new();
+    /// b.extend([2, 3, 1, 2]);
+    /// assert!(a.eq_unordered(&b));
+    ///
+    /// *b.push_back() = 1;
+    /// assert!(!a.eq_unordered(&b));
+    /// ```
+    pub fn eq_unordered(&self, other: &Self) -> bool
+    where
+        T::Item: Ord,
+    {
+        if self.len != other.len {
+            return false;
+        }
+
+        let mut a = self.iter().collect::<Vec<_>>();
+        let mut b = other.iter().collect::<Vec<_>>();
+        a.sort_unstable();
+        b.sort_unstable();
+        a == b
+    }
+
+    /// Returns the number of elements in the `FixedVecDeque` which satisfy the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(buf.count_matching(|v| v % 2 == 0), 2);
+    /// ```
+    pub fn count_matching<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T::Item) -> bool,
+    {
+        let (a, b) = self.as_slices();
+        let mut count = 0;
+
+        for v in a.iter().chain(b) {
+            if pred(v) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Returns a reference to the smallest element in the `FixedVecDeque`, or `None` if it is
+    /// empty.
+    ///
+    /// If several elements are equally small, the one closest to the front is returned.
+    ///
+    /// This isn't called `min`, since that would be shadowed by `Ord::min` which
+    /// `FixedVecDeque` implements whenever its elements do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.min_element(), None);
+    ///
+    /// buf.extend([3, 1, 4, 1, 5]);
+    /// assert_eq!(buf.min_element(), Some(&1));
+    /// ```
+    pub fn min_element(&self) -> Option<&T::Item>
+    where
+        T::Item: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns a reference to the largest element in the `FixedVecDeque`, or `None` if it is
+    /// empty.
+    ///
+    /// If several elements are equally large, the one closest to the back is returned.
+    ///
+    /// This isn't called `max`, since that would be shadowed by `Ord::max` which
+    /// `FixedVecDeque` implements whenever its elements do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.max_element(), None);
+    ///
+    /// buf.extend([9, 1, 4, 1, 5]);
+    /// assert_eq!(buf.max_element(), Some(&5));
+    /// ```
+    pub fn max_element(&self) -> Option<&T::Item>
+    where
+        T::Item: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns a reference to the element in the `FixedVecDeque` which is the smallest with
+    /// respect to the comparison function, or `None` if it is empty.
+    ///
+    /// If several elements are equally small, the one closest to the front is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[f32; 4]>::new();
+    /// buf.extend([2.5, -1.0, 4.0]);
+    ///
+    /// assert_eq!(buf.min_by(|a, b| a.partial_cmp(b).unwrap()), Some(&-1.0));
+    /// ```
+    pub fn min_by<F>(&self, mut compare: F) -> Option<&T::Item>
+    where
+        F: FnMut(&T::Item, &T::Item) -> cmp::Ordering,
+    {
+        self.iter().min_by(|a, b| compare(a, b))
+    }
+
+    /// Returns a reference to the element in the `FixedVecDeque` which is the largest with
+    /// respect to the comparison function, or `None` if it is empty.
+    ///
+    /// If several elements are equally large, the one closest to the back is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[f32; 4]>::new();
+    /// buf.extend([2.5, -1.0, 4.0]);
+    ///
+    /// assert_eq!(buf.max_by(|a, b| a.partial_cmp(b).unwrap()), Some(&4.0));
+    /// ```
+    pub fn max_by<F>(&self, mut compare: F) -> Option<&T::Item>
+    where
+        F: FnMut(&T::Item, &T::Item) -> cmp::Ordering,
+    {
+        self.iter().max_by(|a, b| compare(a, b))
+    }
+
+    /// Returns a reference to the element in the `FixedVecDeque` which gives the smallest value
+    /// from the specified function, or `None` if it is empty.
+    ///
+    /// If several elements are equally small, the one closest to the front is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[i32; 4]>::new();
+    /// buf.extend([-3, 0, 1, 5, -10]);
+    ///
+    /// assert_eq!(buf.min_by_key(|v| v.abs()), Some(&0));
+    /// ```
+    pub fn min_by_key<K, F>(&self, mut f: F) -> Option<&T::Item>
+    where
+        K: Ord,
+        F: FnMut(&T::Item) -> K,
+    {
+        self.iter().min_by_key(|v| f(v))
+    }
+
+    /// Returns a reference to the element in the `FixedVecDeque` which gives the largest value
+    /// from the specified function, or `None` if it is empty.
+    ///
+    /// If several elements are equally large, the one closest to the back is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[i32; 4]>::new();
+    /// buf.extend([-3, 0, 1, 5, -10]);
+    ///
+    /// assert_eq!(buf.max_by_key(|v| v.abs()), Some(&-10));
+    /// ```
+    pub fn max_by_key<K, F>(&self, mut f: F) -> Option<&T::Item>
+    where
+        K: Ord,
+        F: FnMut(&T::Item) -> K,
+    {
+        self.iter().max_by_key(|v| f(v))
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut vector = FixedVecDeque::<[u32; 6]>::new();
+    ///
+    /// *vector.push_back() = 0;
+    /// *vector.push_back() = 1;
+    ///
+    /// *vector.push_front() = 10;
+    /// *vector.push_front() = 9;
+    ///
+    /// vector.as_mut_slices().0[0] = 42;
+    /// vector.as_mut_slices().1[0] = 24;
+    ///
+    /// assert_eq!(vector.as_slices(), (&[42, 10][..], &[24, 1][..]));
+    /// ```
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T::Item], &mut [T::Item]) {
+        if self.is_full() {
+            let head = self.head;
+            let buf = unsafe { self.buffer_as_mut_slice() };
+            let (left, right) = buf.split_at(head);
+            return (right, left);
+        }
+
+        let head = self.head;
+        let tail = self.tail();
+        let buf = unsafe { self.buffer_as_mut_slice() };
+        RingSlices::ring_slices(buf, head, tail)
+    }
+
+    /// Returns a pair of slices which contain, in order, the contents of the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut vector = FixedVecDeque::<[u32; 5]>::new();
+    ///
+    /// *vector.push_back() = 1;
+    /// *vector.push_back() = 2;
+    /// *vector.push_back() = 3;
+    ///
+    /// assert_eq!(vector.as_slices(), (&[1, 2, 3][..], &[][..]));
+    ///
+    /// *vector.push_front() = 4;
+    /// *vector.push_front() = 5;
+    ///
+    /// assert_eq!(vector.as_slices(), (&[5, 4][..], &[1, 2, 3][..]));
+    /// ```
+    #[inline]
+    pub fn as_slices(&self) -> (&[T::Item], &[T::Item]) {
+        let buf = unsafe { self.buffer_as_slice() };
+
+        if self.is_full() {
+            let (left, right) = buf.split_at(self.head);
+            return (right, left);
+        }
+
+        let head = self.head;
+        let tail = T::wrap_sub(head, self.len);
+        RingSlices::ring_slices(buf, head, tail)
+    }
+
+    /// Returns the number of elements in the `FixedVecDeque` together with the pair of slices
+    /// returned by [`as_slices`].
+    ///
+    /// The length is always the sum of the lengths of the two slices.
+    ///
+    /// # Examples
+    ///
+    /// Writing the contents of a buffer in a length-prefixed format:
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u8; 4]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
+    ///
+    /// let (len, a, b) = buf.as_parts();
+    ///
+    /// let mut out = Vec::new();
+    /// out.push(len as u8);
+    /// out.extend_from_slice(a);
+    /// out.extend_from_slice(b);
+    ///
+    /// assert_eq!(out, vec![4, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// [`as_slices`]: struct.FixedVecDeque.html#method.as_slices
+    pub fn as_parts(&self) -> (usize, &[T::Item], &[T::Item]) {
+        let (a, b) = self.as_slices();
+        (self.len, a, b)
+    }
+
+    /// Returns the contents of the `FixedVecDeque` as an array of two slices, together with the
+    /// number of slices which are non-empty.
+    ///
+    /// The slices are the same as the ones returned by [`as_slices`]. The count is `0` if the
+    /// `FixedVecDeque` is empty, `1` if its contents are contiguous and `2` if they wrap around,
+    /// so `&slices[..count]` only contains non-empty slices.
+    ///
+    /// # Examples
+    ///
+    /// Building scatter-gather vectors for a vectored write:
+    ///
+    /// ```
+    /// use std::io::IoSlice;
+    ///
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u8; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// let (slices, count) = buf.iovecs();
+    /// assert_eq!(count, 1);
+    ///
+    /// buf.extend([4, 5]);
+    ///
+    /// let (slices, count) = buf.iovecs();
+    /// assert_eq!(count, 2);
+    ///
+    /// let io = slices[..count].iter().map(|s| IoSlice::new(s)).collect::<Vec<_>>();
+    /// assert_eq!(&*io[0], &[2, 3, 4]);
+    /// assert_eq!(&*io[1], &[5]);
+    /// ```
+    ///
+    /// [`as_slices`]: struct.FixedVecDeque.html#method.as_slices
+    pub fn iovecs(&self) -> ([&[T::Item]; 2], usize) {
+        let (a, b) = self.as_slices();
+        let count = usize::from(!a.is_empty()) + usize::from(!b.is_empty());
+        ([a, b], count)
+    }
+
+    /// Divides the `FixedVecDeque` into two mutable views at the logical index `mid`.
+    ///
+    /// The first view contains the elements `[0, mid)` and the second the elements `[mid, len)`.
+    /// The views don't overlap, so both can be mutated at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
+    ///
+    /// let (mut left, right) = buf.split_at_mut(1);
+    /// assert_eq!(left.len(), 1);
+    /// assert_eq!(right.len(), 3);
+    ///
+    /// for (l, r) in left.iter_mut().zip(right) {
+    ///     std::mem::swap(l, r);
+    /// }
+    ///
+    /// assert_eq!(buf, [3, 2, 4, 5]);
+    /// ```
+    pub fn split_at_mut(&mut self, mid: usize) -> (SplitSliceMut<'_, T>, SplitSliceMut<'_, T>) {
+        assert!(mid <= self.len, "mid > len");
+
+        let data = self.data.ptr_mut();
+
+        let left = IterMut {
+            data,
+            head: self.ptr_index(mid),
+            len: mid,
+            marker: marker::PhantomData,
+        };
+
+        let right = IterMut {
+            data,
+            head: self.head,
+            len: self.len - mid,
+            marker: marker::PhantomData,
+        };
+
+        (SplitSliceMut { iter: left }, SplitSliceMut { iter: right })
+    }
+
+    /// Returns a view of the specified logical range of the `FixedVecDeque`.
+    ///
+    /// Since the range might wrap around the underlying buffer, it's not always possible to
+    /// represent it as a single slice. The returned [`RingView`] instead provides access to it
+    /// as a pair of slices.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
+    /// buf.extend([3, 4, 5]);
+    /// *buf.push_front() = 2;
+    /// *buf.push_front() = 1;
+    ///
+    /// let view = buf.slice(1..4);
+    /// assert_eq!(view.len(), 3);
+    /// assert_eq!(view.get(0), Some(&2));
+    /// assert_eq!(view.as_slices(), (&[2][..], &[3, 4][..]));
+    /// assert_eq!(view.into_iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    /// ```
+    ///
+    /// [`RingView`]: struct.RingView.html
+    pub fn slice<R>(&self, range: R) -> RingView<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_bounds(range);
+        let (a, b) = self.as_slices();
+
+        let front = &a[cmp::min(start, a.len())..cmp::min(end, a.len())];
+        let back = &b[start.saturating_sub(a.len())..end.saturating_sub(a.len())];
+        RingView { front, back }
+    }
+
+    /// Returns the contents of the `FixedVecDeque` as a single mutable slice, rearranging the
+    /// underlying buffer so that they are stored contiguously if needed.
+    ///
+    /// The order of the elements is preserved. If the contents are already contiguous, nothing
+    /// is moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
+    /// buf.extend([3, 4]);
+    /// *buf.push_front() = 2;
+    /// *buf.push_front() = 1;
+    /// assert_eq!(buf.as_slices(), (&[1, 2][..], &[3, 4][..]));
+    ///
+    /// buf.contiguous_mut()[0] = 0;
+    /// assert_eq!(buf.as_slices(), (&[0, 2, 3, 4][..], &[][..]));
+    /// ```
+    pub fn contiguous_mut(&mut self) -> &mut [T::Item] {
+        if self.as_slices().1.is_empty() {
+            return self.as_mut_slices().0;
+        }
+
+        // NB: all slots in the underlying buffer hold initialized values, so the whole buffer can
+        // be rotated such that the front ends up at the physical index 0.
+        let tail = self.tail();
+        unsafe { self.buffer_as_mut_slice() }.rotate_left(tail);
+        self.head = if self.len == T::size() { 0 } else { self.len };
+        self.as_mut_slices().0
+    }
+
+    /// Rearranges the underlying buffer of the `FixedVecDeque` so that the front is stored at
+    /// physical index 0, and returns the number of live elements which had to be moved.
+    ///
+    /// Unlike [`contiguous_mut`], this also moves elements which are already stored contiguously
+    /// if they don't start at the beginning of the underlying buffer. If the front already is at
+    /// physical index 0, nothing is moved and this returns 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.compact(), 0);
+    ///
+    /// buf.pop_front();
+    /// assert_eq!(buf.compact(), 2);
+    /// assert_eq!(buf.tail_index(), 0);
+    /// assert_eq!(buf, [2, 3]);
+    /// ```
+    ///
+    /// [`contiguous_mut`]: struct.FixedVecDeque.html#method.contiguous_mut
+    pub fn compact(&mut self) -> usize {
+        if self.len == 0 {
+            self.head = 0;
+            return 0;
+        }
+
+        let tail = self.tail();
+
+        if tail == 0 {
+            return 0;
+        }
+
+        // NB: every live element changes position when the buffer is rotated.
+        unsafe { self.buffer_as_mut_slice() }.rotate_left(tail);
+        self.head = if self.len == T::size() { 0 } else { self.len };
+        self.len
+    }
+
+    /// Rearranges the underlying buffer of the `FixedVecDeque` so that the front is stored at the
+    /// physical index `physical`, preserving the order of the elements.
+    ///
+    /// After this, [`tail_index`] returns `physical`. This is O(capacity) unless the front is
+    /// already stored at `physical`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `physical` is greater than or equal to the [`capacity`] of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// buf.align_front_to(2);
+    /// assert_eq!(buf.tail_index(), 2);
+    /// assert_eq!(buf.head_index(), 1);
+    /// assert_eq!(buf.as_slices(), (&[1, 2][..], &[3][..]));
+    /// ```
+    ///
+    /// [`tail_index`]: struct.FixedVecDeque.html#method.tail_index
+    /// [`capacity`]: struct.FixedVecDeque.html#method.capacity
+    pub fn align_front_to(&mut self, physical: usize) {
+        assert!(
+            physical < T::size(),
+            "physical index {} is out of bounds for capacity {}",
+            physical,
+            T::size()
+        );
+
+        let tail = self.tail();
+
+        if tail == physical {
+            return;
+        }
+
+        if self.len != 0 {
+            let data = unsafe { self.buffer_as_mut_slice() };
+
+            if physical > tail {
+                data.rotate_right(physical - tail);
+            } else {
+                data.rotate_left(tail - physical);
+            }
+        }
+
+        self.head = T::wrap_add(physical, self.len);
+    }
+
+    /// Rearranges the underlying buffer of the `FixedVecDeque` so that its contents are stored
+    /// contiguously, and returns a mutable slice to them.
+    ///
+    /// This is an alias for [`contiguous_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
+    /// buf.extend([3, 4]);
+    /// *buf.push_front() = 2;
+    /// *buf.push_front() = 1;
+    ///
+    /// buf.make_contiguous()[0] = 0;
+    /// assert_eq!(buf.as_slices(), (&[0, 2, 3, 4][..], &[][..]));
+    /// ```
+    ///
+    /// [`contiguous_mut`]: struct.FixedVecDeque.html#method.contiguous_mut
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut [T::Item] {
+        self.contiguous_mut()
+    }
+
+    /// Rotates the `FixedVecDeque` so that the element at `index` becomes the new front.
+    ///
+    /// Elements before `index` are moved to the back, keeping their order. If the buffer is full
+    /// this only adjusts where the logical front is, otherwise the elements are rotated in the
+    /// underlying buffer.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    ///
+    /// buf.rotate_to_front(2);
+    /// assert_eq!(buf.front(), Some(&3));
+    /// assert_eq!(buf, [3, 4, 1, 2]);
+    /// ```
+    pub fn rotate_to_front(&mut self, index: usize) {
+        assert!(
+            index < self.len,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len,
+            index
+        );
+
+        self.rotate_left(index);
+    }
+
+    /// Rotates the `FixedVecDeque` `n` places to the left, so that the element at index `n`
+    /// becomes the new front and the first `n` elements are moved to the back.
+    ///
+    /// If the buffer is full, this only adjusts where the logical front is. Otherwise the
+    /// elements are made contiguous and rotated in place, which runs in time proportional to the
+    /// capacity regardless of `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than `len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 8]>::new();
+    /// buf.extend(0..5);
+    ///
+    /// buf.rotate_left(2);
+    /// assert_eq!(buf, [2, 3, 4, 0, 1]);
+    ///
+    /// buf.rotate_left(5);
+    /// assert_eq!(buf, [2, 3, 4, 0, 1]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation {} is greater than the len {}",
+            n,
+            self.len
+        );
+
+        if self.is_full() {
+            self.head = T::wrap_add(self.head, n);
+            return;
+        }
+
+        // NB: slice rotation is done in-place with a linear number of element moves, which is the
+        // same as what it takes to make the buffer contiguous.
+        self.contiguous_mut().rotate_left(n);
+    }
+
+    /// Rotates the `FixedVecDeque` `n` places to the right, so that the last `n` elements are
+    /// moved to the front.
+    ///
+    /// This has the same performance characteristics as [`rotate_left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than `len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 8]>::new();
+    /// buf.extend(0..5);
+    ///
+    /// buf.rotate_right(2);
+    /// assert_eq!(buf, [3, 4, 0, 1, 2]);
+    /// ```
+    ///
+    /// [`rotate_left`]: struct.FixedVecDeque.html#method.rotate_left
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation {} is greater than the len {}",
+            n,
+            self.len
+        );
+
+        self.rotate_left(self.len - n);
+    }
+
+    /// Sorts the `FixedVecDeque` with a comparator function.
+    ///
+    /// This sort is stable, elements which compare as equal keep their original front-to-back
+    /// order. The underlying buffer is made contiguous through [`contiguous_mut`] after which
+    /// the slice of the elements is sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[(u32, char); 4]>::new();
+    /// buf.extend([(2, 'a'), (1, 'b'), (2, 'c')]);
+    /// *buf.push_front() = (1, 'd');
+    ///
+    /// buf.sort_stable_by(|a, b| a.0.cmp(&b.0));
+    /// assert_eq!(buf, [(1, 'd'), (1, 'b'), (2, 'a'), (2, 'c')]);
+    /// ```
+    ///
+    /// [`contiguous_mut`]: struct.FixedVecDeque.html#method.contiguous_mut
+    pub fn sort_stable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T::Item, &T::Item) -> cmp::Ordering,
+    {
+        self.contiguous_mut().sort_by(compare);
+    }
+
+    /// Returns a slice of the contents of the `FixedVecDeque` if they are stored contiguously in
+    /// the underlying buffer, or `None` if they wrap around its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut vector = FixedVecDeque::<[u32; 5]>::new();
+    /// assert_eq!(vector.as_contiguous_slice(), Some(&[][..]));
+    ///
+    /// *vector.push_back() = 1;
+    /// *vector.push_back() = 2;
+    /// assert_eq!(vector.as_contiguous_slice(), Some(&[1, 2][..]));
+    ///
+    /// *vector.push_front() = 3;
+    /// assert_eq!(vector.as_contiguous_slice(), None);
+    /// ```
+    pub fn as_contiguous_slice(&self) -> Option<&[T::Item]> {
+        match self.as_slices() {
+            (a, []) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the elements of the `FixedVecDeque` are stored contiguously in the
+    /// underlying buffer, or `false` if they wrap around its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    /// assert!(buf.is_contiguous());
+    ///
+    /// *buf.push_back() = 5;
+    /// assert!(!buf.is_contiguous());
+    /// ```
+    #[inline]
+    pub fn is_contiguous(&self) -> bool {
+        self.wrap_point().is_none()
+    }
+
+    /// Returns the logical index at which the elements of the `FixedVecDeque` wrap around the end
+    /// of the underlying buffer, or `None` if they are stored contiguously.
+    ///
+    /// When wrapped, this is the index of the first element stored at the start of the buffer and
+    /// it's the same as the length of the first slice returned by [`as_slices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    /// assert_eq!(buf.wrap_point(), None);
+    ///
+    /// *buf.push_back() = 5;
+    /// assert_eq!(buf, [2, 3, 4, 5]);
+    /// assert_eq!(buf.wrap_point(), Some(3));
+    /// assert_eq!(buf.as_slices(), (&[2, 3, 4][..], &[5][..]));
+    /// ```
+    ///
+    /// [`as_slices`]: struct.FixedVecDeque.html#method.as_slices
+    pub fn wrap_point(&self) -> Option<usize> {
+        let to_end = T::size() - self.tail();
+
+        if self.len > to_end {
+            Some(to_end)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves an element in the `FixedVecDeque` by index.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
+    /// *buf.push_back() = 3;
+    /// *buf.push_back() = 4;
+    /// *buf.push_back() = 5;
+    /// assert_eq!(buf.get(1), Some(&4));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T::Item> {
+        if index < self.len {
+            let off = self.ptr_index(index);
+            Some(unsafe { self.buffer(off) })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves an element in the `FixedVecDeque` mutably by index.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
+    /// *buf.push_back() = 3;
+    /// *buf.push_back() = 4;
+    /// *buf.push_back() = 5;
+    /// if let Some(elem) = buf.get_mut(1) {
+    ///     *elem = 7;
+    /// }
+    ///
+    /// assert_eq!(buf[1], 7);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T::Item> {
+        if index < self.len {
+            let off = self.ptr_index(index);
+            Some(unsafe { self.buffer_mut(off) })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves mutable references to the two distinct elements at indices `i` and `j`.
+    ///
+    /// Returns `None` if `i` and `j` are equal, or if either index is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// if let Some((a, b)) = buf.pair_mut(0, 2) {
+    ///     *a += 10;
+    ///     *b += 20;
+    /// }
+    ///
+    /// assert_eq!(buf, [11, 2, 23]);
+    /// assert!(buf.pair_mut(1, 1).is_none());
+    /// assert!(buf.pair_mut(1, 3).is_none());
+    /// ```
+    pub fn pair_mut(&mut self, i: usize, j: usize) -> Option<(&mut T::Item, &mut T::Item)> {
+        if i == j || i >= self.len || j >= self.len {
+            return None;
+        }
+
+        let ri = self.ptr_index(i);
+        let rj = self.ptr_index(j);
+        let d = self.data.ptr_mut();
+        // Safety: both indexes are in bounds and distinct, so the references don't alias.
+        unsafe { Some((&mut *d.add(ri), &mut *d.add(rj))) }
+    }
+
+    /// Replaces the element at `index` with `value`, returning the old element. Returns `None`
+    /// and drops `value` if `index` is out of bounds.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// Note that unlike most other operations, this moves the old element out of the
+    /// `FixedVecDeque` instead of leaving it in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// assert_eq!(buf.replace(1, 7), Some(2));
+    /// assert_eq!(buf.replace(3, 8), None);
+    /// assert_eq!(buf, [1, 7, 3]);
+    /// ```
+    pub fn replace(&mut self, index: usize, value: T::Item) -> Option<T::Item> {
+        self.get_mut(index).map(|elem| mem::replace(elem, value))
+    }
+
+    /// Swaps elements at indices `i` and `j`.
+    ///
+    /// `i` and `j` may be equal.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.push_back() = 3;
+    /// *buf.push_back() = 4;
+    /// *buf.push_back() = 5;
+    /// assert_eq!(buf, [3, 4, 5]);
+    /// buf.swap(0, 2);
+    /// assert_eq!(buf, [5, 4, 3]);
+    /// ```
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < T::size());
+        assert!(j < T::size());
+        let ri = self.ptr_index(i);
+        let rj = self.ptr_index(j);
+        let d = self.data.ptr_mut();
+        unsafe { ptr::swap(d.add(ri), d.add(rj)) }
+    }
+
+    /// Swaps elements at indices `i` and `j`, returning `false` without doing anything if either
+    /// index is out of bounds.
+    ///
+    /// `i` and `j` may be equal. This is the non-panicking version of [`swap`].
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([3, 4, 5]);
+    ///
+    /// assert!(buf.swap_logical(0, 2));
+    /// assert_eq!(buf, [5, 4, 3]);
+    ///
+    /// assert!(!buf.swap_logical(0, 3));
+    /// assert_eq!(buf, [5, 4, 3]);
+    /// ```
+    ///
+    /// [`swap`]: struct.FixedVecDeque.html#method.swap
+    pub fn swap_logical(&mut self, i: usize, j: usize) -> bool {
+        if i >= self.len || j >= self.len {
+            return false;
+        }
+
+        let ri = self.ptr_index(i);
+        let rj = self.ptr_index(j);
+        let d = self.data.ptr_mut();
+        unsafe { ptr::swap(d.add(ri), d.add(rj)) }
+        true
+    }
+
+    /// Swaps the entire contents of this `FixedVecDeque` with `other`.
+    ///
+    /// This is the same as calling [`mem::swap`] on the two deques. No elements are cloned, moved
+    /// individually or dropped, the underlying buffers are simply exchanged. For array-backed
+    /// deques this is a memory copy of both buffers, while for [`BoxedArray`] it only swaps the
+    /// pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut a = FixedVecDeque::<[u32; 4]>::new();
+    /// a.extend([1, 2, 3]);
+    ///
+    /// let mut b = FixedVecDeque::<[u32; 4]>::new();
+    /// b.extend([4]);
+    ///
+    /// a.swap_contents(&mut b);
+    /// assert_eq!(a, [4]);
+    /// assert_eq!(b, [1, 2, 3]);
+    /// ```
+    ///
+    /// [`mem::swap`]: https://doc.rust-lang.org/std/mem/fn.swap.html
+    /// [`BoxedArray`]: struct.BoxedArray.html
+    #[inline]
+    pub fn swap_contents(&mut self, other: &mut FixedVecDeque<T>) {
+        mem::swap(self, other);
+    }
+
+    /// Turn `i`, which is a zero-based offset into a ptr index that wraps around the size of this
+    /// container.
+    #[inline]
+    fn ptr_index(&self, i: usize) -> usize {
+        T::wrap_add(self.tail(), i)
+    }
+
+    /// Get index of tail.
+    #[inline]
+    fn tail(&self) -> usize {
+        T::wrap_sub(self.head, self.len)
+    }
+
+    /// Resolve a range into logical `(start, end)` offsets, panicking if it is out of bounds.
+    fn range_bounds<R>(&self, range: R) -> (usize, usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n
+                .checked_add(1)
+                .expect("attempted to index slice from after maximum usize"),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n
+                .checked_add(1)
+                .expect("attempted to index slice up to maximum usize"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+
+        assert!(
+            start <= end,
+            "slice index starts at {} but ends at {}",
+            start,
+            end
+        );
+
+        assert!(
+            end <= self.len,
+            "range end index {} out of range for slice of length {}",
+            end,
+            self.len
+        );
+
+        (start, end)
+    }
+
+    /// Turn ptr into a slice
+    #[inline]
+    unsafe fn buffer_as_slice(&self) -> &[T::Item] {
+        slice::from_raw_parts(self.data.ptr(), T::size())
+    }
+
+    /// Turn ptr into a mut slice
+    #[inline]
+    unsafe fn buffer_as_mut_slice(&mut self) -> &mut [T::Item] {
+        slice::from_raw_parts_mut(self.data.ptr_mut(), T::size())
+    }
+
+    /// Takes a reference of a value from the buffer.
+    #[inline]
+    unsafe fn buffer(&self, off: usize) -> &T::Item {
+        &*self.data.ptr().add(off)
+    }
+
+    /// Takes a mutable reference of a value from the buffer.
+    #[inline]
+    unsafe fn buffer_mut(&mut self, off: usize) -> &mut T::Item {
+        &mut *self.data.ptr_mut().add(off)
+    }
+
+    #[inline]
+    unsafe fn buffer_read(&mut self, off: usize) -> T::Item {
+        debug_assert!(off < T::size());
+        ptr::read(self.data.ptr().add(off))
+    }
+
+    #[inline]
+    unsafe fn buffer_write(&mut self, off: usize, data: T::Item) {
+        debug_assert!(off < T::size());
+        ptr::write(self.data.ptr_mut().add(off), data);
+    }
+
+    /// Copies a contiguous block of memory len long from src to dst
+    #[inline]
+    unsafe fn copy(&mut self, dst: usize, src: usize, len: usize) {
+        debug_assert!(
+            dst + len <= T::size(),
+            "cpy dst={} src={} len={} cap={}",
+            dst,
+            src,
+            len,
+            T::size()
+        );
+
+        debug_assert!(
+            src + len <= T::size(),
+            "cpy dst={} src={} len={} cap={}",
+            dst,
+            src,
+            len,
+            T::size()
+        );
+
+        let m = self.data.ptr_mut();
+        ptr::copy(m.add(src), m.add(dst), len);
+    }
+}
+
+impl<T> FixedVecDeque<T>
+where
+    T: Array,
+    T::Item: Clone,
+{
+    /// Copies the live elements of the `FixedVecDeque` into a new vector, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 3]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    /// assert_eq!(buf.to_vec(), vec![2, 3, 4]);
+    /// assert_eq!(buf, [2, 3, 4]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T::Item> {
+        let (a, b) = self.as_slices();
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend_from_slice(a);
+        vec.extend_from_slice(b);
+        vec
+    }
+
+    /// Takes an owned [`Snapshot`] of the live elements of the `FixedVecDeque`, front to back.
+    ///
+    /// The snapshot doesn't borrow from the `FixedVecDeque`, so it can be sent to other threads
+    /// while the deque keeps being modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 3]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    ///
+    /// let snapshot = buf.snapshot();
+    /// *buf.push_back() = 5;
+    ///
+    /// let sum = thread::spawn(move || snapshot.iter().sum::<u32>()).join().unwrap();
+    /// assert_eq!(sum, 9);
+    /// ```
+    ///
+    /// [`Snapshot`]: struct.Snapshot.html
+    pub fn snapshot(&self) -> Snapshot<T::Item> {
+        Snapshot {
+            data: self.to_vec().into_boxed_slice(),
+        }
+    }
+
+    /// Clears `dst` and fills it with clones of the elements of `self` followed by the elements
+    /// of `other`, front to back.
+    ///
+    /// Elements are only added until `dst` is full, any remaining elements are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut a = FixedVecDeque::<[u32; 4]>::new();
+    /// a.extend([1, 2]);
+    /// let mut b = FixedVecDeque::<[u32; 4]>::new();
+    /// b.extend([3, 4, 5]);
+    ///
+    /// let mut dst = FixedVecDeque::<[u32; 8]>::new();
+    /// a.concat_into(&b, &mut dst);
+    /// assert_eq!(dst, [1, 2, 3, 4, 5]);
+    ///
+    /// let mut dst = FixedVecDeque::<[u32; 3]>::new();
+    /// a.concat_into(&b, &mut dst);
+    /// assert_eq!(dst, [1, 2, 3]);
+    /// ```
+    pub fn concat_into<U>(&self, other: &Self, dst: &mut FixedVecDeque<U>)
+    where
+        U: Array<Item = T::Item>,
+    {
+        dst.clear();
+
+        for elem in self.iter().chain(other.iter()).take(U::size()) {
+            dst.push_back().clone_from(elem);
+        }
+    }
+
+    /// Overwrites the elements in the specified logical range of the `FixedVecDeque` with clones
+    /// of `value`.
+    ///
+    /// Element at index 0 is the front of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater
+    /// than the length of the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 5]>::new();
+    /// buf.extend([1, 2, 3, 4, 5]);
+    ///
+    /// buf.fill_range(1..3, 0);
+    /// assert_eq!(buf, [1, 0, 0, 4, 5]);
+    ///
+    /// buf.fill_range(.., 7);
+    /// assert_eq!(buf, [7, 7, 7, 7, 7]);
+    /// ```
+    pub fn fill_range<R>(&mut self, range: R, value: T::Item)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = self.range_bounds(range);
+        let (a, b) = self.as_mut_slices();
+        let split = a.len();
+
+        a[cmp::min(start, split)..cmp::min(end, split)].fill(value.clone());
+        b[start.saturating_sub(split)..end.saturating_sub(split)].fill(value);
+    }
+
+    /// Modifies the `FixedVecDeque` in-place so that `len()` is equal to new_len,
+    /// either by removing excess elements from the back or by appending clones of `value`
+    /// to the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is longer than the [`capacity`] of this buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 8]>::new();
+    /// *buf.push_back() = 5;
+    /// *buf.push_back() = 10;
+    /// *buf.push_back() = 15;
+    /// assert_eq!(buf, [5, 10, 15]);
+    ///
+    /// buf.resize(2, 0);
+    /// assert_eq!(buf, [5, 10]);
+    ///
+    /// buf.resize(5, 20);
+    /// assert_eq!(buf, [5, 10, 20, 20, 20]);
+    /// ```
+    ///
+    /// [`capacity`]: struct.FixedVecDeque.html#method.capacity
+    pub fn resize(&mut self, new_len: usize, value: T::Item) {
+        assert!(new_len < T::size(), "resize beyond capacity");
+
+        let len = self.len();
+
+        if new_len > len {
+            self.extend(repeat(value).take(new_len - len))
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+impl<T> Default for FixedVecDeque<T>
+where
+    T: Array,
+    T::Item: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> hash::Hash for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // NB: this mirrors the implementation for `[T]`, which prefixes the length before hashing
+        // its elements. For hashers which treat consecutive writes as one stream, like the
+        // `DefaultHasher`, the hash of a deque is the same as the hash of a slice holding the same
+        // elements.
+        state.write_usize(self.len());
+        let (a, b) = self.as_slices();
+        hash::Hash::hash_slice(a, state);
+        hash::Hash::hash_slice(b, state);
+    }
+}
+
+impl<T> Index<usize> for FixedVecDeque<T>
+where
+    T: Array,
+{
+    type Output = T::Item;
+
+    fn index(&self, index: usize) -> &T::Item {
+        let len = self.len;
+
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                len, index
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for FixedVecDeque<T>
+where
+    T: Array,
+{
+    fn index_mut(&mut self, index: usize) -> &mut T::Item {
+        let len = self.len;
+
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                len, index
+            ),
+        }
+    }
+}
+
+/// An iterator over the elements of a `FixedVecDeque`.
+///
+/// This `struct` is created by the [`iter`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`iter`]: struct.FixedVecDeque.html#method.iter
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct Iter<'a, T: 'a>
+where
+    T: Array,
+{
+    data: *const T::Item,
+    head: usize,
+    len: usize,
+    marker: marker::PhantomData<&'a ()>,
+}
+
+impl<'a, T: 'a> Clone for Iter<'a, T>
+where
+    T: Array,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: 'a> Copy for Iter<'a, T> where T: Array {}
+
+impl<'a, T: 'a> fmt::Debug for Iter<'a, T>
+where
+    T: Array,
+    T::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+impl<'a, T: 'a> Iter<'a, T>
+where
+    T: Array,
+{
+    /// The one or two contiguous runs making up the remaining elements, front to back.
+    fn runs(&self) -> (&'a [T::Item], &'a [T::Item]) {
+        let tail = T::wrap_sub(self.head, self.len);
+        let first = cmp::min(self.len, T::size() - tail);
+
+        unsafe {
+            (
+                slice::from_raw_parts(self.data.add(tail), first),
+                slice::from_raw_parts(self.data, self.len - first),
+            )
+        }
+    }
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T>
+where
+    T: Array,
+{
+    type Item = &'a T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let tail = T::wrap_sub(self.head, self.len);
+        self.len -= 1;
+        Some(unsafe { &*self.data.add(tail) })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        self.len -= n;
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let back = T::wrap_sub(self.head, 1);
+        Some(unsafe { &*self.data.add(back) })
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let (a, b) = self.runs();
+        let acc = a.iter().fold(init, &mut f);
+        b.iter().fold(acc, f)
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        let (a, b) = self.runs();
+        a.iter().for_each(&mut f);
+        b.iter().for_each(f);
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T>
+where
+    T: Array,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head = T::wrap_sub(self.head, 1);
+        self.len -= 1;
+        Some(unsafe { &*self.data.add(self.head) })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> where T: Array {}
+
+/// An iterator over the elements of a `FixedVecDeque`.
+///
+/// This `struct` is created by the [`iter`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`iter`]: struct.FixedVecDeque.html#method.iter
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct IterMut<'a, T: 'a>
+where
+    T: Array,
+{
+    data: *mut T::Item,
+    head: usize,
+    len: usize,
+    marker: marker::PhantomData<&'a ()>,
+}
+
+impl<'a, T: 'a> IterMut<'a, T>
+where
+    T: Array,
+{
+    /// Returns a read-only iterator over the elements which haven't been yielded yet.
+    ///
+    /// The returned iterator borrows the `IterMut`, so it can be used for a read pass over the
+    /// remaining elements before continuing to mutate them.
+    ///
+    /// Note that this can't start over from the first element, since elements which have already
+    /// been yielded might still be borrowed mutably. To read the whole sequence again, finish
+    /// using the `IterMut` and call [`iter`] on the `FixedVecDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    ///
+    /// let mut it = buf.iter_mut();
+    /// *it.next().unwrap() = 10;
+    ///
+    /// let sum = it.as_iter().sum::<u32>();
+    ///
+    /// for v in it {
+    ///     *v = sum;
+    /// }
+    ///
+    /// assert_eq!(buf, [10, 9, 9, 9]);
+    /// ```
+    ///
+    /// [`iter`]: struct.FixedVecDeque.html#method.iter
+    pub fn as_iter(&self) -> Iter<'_, T> {
+        Iter {
+            data: self.data,
+            head: self.head,
+            len: self.len,
+            marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a> fmt::Debug for IterMut<'a, T>
+where
+    T: Array,
+    T::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_iter()).finish()
+    }
+}
+
+impl<'a, T: 'a> Iterator for IterMut<'a, T>
+where
+    T: Array,
+{
+    type Item = &'a mut T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let tail = T::wrap_sub(self.head, self.len);
+        self.len -= 1;
+        Some(unsafe { &mut *self.data.add(tail) })
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        self.len -= n;
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let back = T::wrap_sub(self.head, 1);
+        Some(unsafe { &mut *self.data.add(back) })
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T>
+where
+    T: Array,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // NB: the front is yielded from `head - len`, so by decrementing both `head` and `len` the
+        // two ends can never hand out the same slot.
+        self.head = T::wrap_sub(self.head, 1);
+        self.len -= 1;
+        Some(unsafe { &mut *self.data.add(self.head) })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for IterMut<'a, T> where T: Array {}
+
+/// A draining iterator over the elements of a `FixedVecDeque`.
+///
+/// This `struct` is created by the [`drain`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`drain`]: struct.FixedVecDeque.html#method.drain
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct Drain<'a, T: 'a>
+where
+    T: Array,
+    T::Item: Default,
+{
+    deq: &'a mut FixedVecDeque<T>,
+}
+
+impl<'a, T: 'a> Iterator for Drain<'a, T>
+where
+    T: Array,
+    T::Item: Default,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.deq.pop_front().map(mem::take)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deq.len, Some(self.deq.len))
+    }
+}
+
+impl<'a, T: 'a> Drop for Drain<'a, T>
+where
+    T: Array,
+    T::Item: Default,
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// A draining iterator over the elements at the front of a `FixedVecDeque` which satisfy a
+/// predicate.
+///
+/// This `struct` is created by the [`drain_while_front`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`drain_while_front`]: struct.FixedVecDeque.html#method.drain_while_front
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct DrainWhile<'a, T: 'a, F>
+where
+    T: Array,
+    T::Item: Default,
+{
+    deq: &'a mut FixedVecDeque<T>,
+    pred: F,
+    done: bool,
+}
+
+impl<'a, T: 'a, F> Iterator for DrainWhile<'a, T, F>
+where
+    T: Array,
+    T::Item: Default,
+    F: FnMut(&T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.deq.front() {
+            Some(front) if (self.pred)(front) => self.deq.pop_front().map(mem::take),
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, Some(self.deq.len))
+        }
+    }
+}
+
+/// An entry for the back of a `FixedVecDeque`, which might be appended to it.
+///
+/// This `struct` is created by the [`back_entry`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`back_entry`]: struct.FixedVecDeque.html#method.back_entry
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct BackEntry<'a, T: 'a>
+where
+    T: Array,
+{
+    deq: &'a mut FixedVecDeque<T>,
+}
+
+impl<'a, T: 'a> BackEntry<'a, T>
+where
+    T: Array,
+{
+    /// Provides a reference to the current back element, or `None` if the `FixedVecDeque` is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert_eq!(buf.back_entry().back(), None);
+    /// *buf.push_back() = 1;
+    /// assert_eq!(buf.back_entry().back(), Some(&1));
+    /// ```
+    pub fn back(&self) -> Option<&T::Item> {
+        self.deq.back()
+    }
+
+    /// Returns the number of elements in the `FixedVecDeque` the entry belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2]);
+    /// assert_eq!(buf.back_entry().len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.deq.len()
+    }
+
+    /// Returns `true` if the `FixedVecDeque` the entry belongs to is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert!(buf.back_entry().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.deq.is_empty()
+    }
+
+    /// Commits the entry by appending a `Default::default()` element through [`push_back`],
+    /// returning a mutable reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Calling this function will panic if the circular buffer is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// *buf.back_entry().or_default() += 1;
+    /// *buf.back_entry().or_default() += 2;
+    /// assert_eq!(buf, [1, 2]);
+    /// ```
+    ///
+    /// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+    pub fn or_default(self) -> &'a mut T::Item
+    where
+        T::Item: Default,
+    {
+        let elem = self.deq.push_back();
+        *elem = T::Item::default();
+        elem
+    }
+
+    /// Discards the entry, leaving the `FixedVecDeque` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.back_entry().discard();
+    /// assert!(buf.is_empty());
+    /// ```
+    pub fn discard(self) {}
+}
+
+/// An iterator over overlapping windows of elements of a `FixedVecDeque`.
+///
+/// This `struct` is created by the [`windows`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`windows`]: struct.FixedVecDeque.html#method.windows
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct Windows<'a, T: 'a>
+where
+    T: Array,
+{
+    iter: Iter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T: 'a> Iterator for Windows<'a, T>
+where
+    T: Array,
+{
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len < self.size {
+            return None;
+        }
+
+        let tail = T::wrap_sub(self.iter.head, self.iter.len);
+        self.iter.len -= 1;
+
+        Some(Iter {
+            data: self.iter.data,
+            head: T::wrap_add(tail, self.size),
+            len: self.size,
+            marker: marker::PhantomData,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.iter.len + 1).saturating_sub(self.size);
+        (len, Some(len))
+    }
+}
+
+/// An iterator over non-overlapping chunks of elements of a `FixedVecDeque`, starting at the
+/// front.
+///
+/// This `struct` is created by the [`chunks`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`chunks`]: struct.FixedVecDeque.html#method.chunks
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct Chunks<'a, T: 'a>
+where
+    T: Array,
+{
+    iter: Iter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T: 'a> Iterator for Chunks<'a, T>
+where
+    T: Array,
+{
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len == 0 {
+            return None;
+        }
+
+        let len = cmp::min(self.size, self.iter.len);
+        let tail = T::wrap_sub(self.iter.head, self.iter.len);
+        self.iter.len -= len;
+
+        Some(Iter {
+            data: self.iter.data,
+            head: T::wrap_add(tail, len),
+            len,
+            marker: marker::PhantomData,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len / self.size + usize::from(self.iter.len % self.size != 0);
+        (len, Some(len))
+    }
+}
+
+/// An iterator over non-overlapping chunks of a `FixedVecDeque`, starting at the back.
+///
+/// This `struct` is created by the [`rchunks`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`rchunks`]: struct.FixedVecDeque.html#method.rchunks
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct RChunks<'a, T: 'a>
+where
+    T: Array,
+{
+    iter: Iter<'a, T>,
+    size: usize,
+}
+
+impl<'a, T: 'a> Iterator for RChunks<'a, T>
+where
+    T: Array,
+{
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len == 0 {
+            return None;
+        }
+
+        let len = cmp::min(self.size, self.iter.len);
+        let head = self.iter.head;
+        self.iter.head = T::wrap_sub(head, len);
+        self.iter.len -= len;
+
+        Some(Iter {
+            data: self.iter.data,
+            head,
+            len,
+            marker: marker::PhantomData,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len / self.size + usize::from(self.iter.len % self.size != 0);
+        (len, Some(len))
+    }
+}
+
+/// A view of a logical range of a `FixedVecDeque`.
+///
+/// This `struct` is created by the [`slice`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`slice`]: struct.FixedVecDeque.html#method.slice
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct RingView<'a, T: 'a>
+where
+    T: Array,
+{
+    front: &'a [T::Item],
+    back: &'a [T::Item],
+}
+
+impl<'a, T: 'a> RingView<'a, T>
+where
+    T: Array,
+{
+    /// Returns the number of elements in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.slice(1..).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns `true` if the view is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert!(buf.slice(1..1).is_empty());
+    /// assert!(!buf.slice(1..2).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    /// Retrieves an element in the view by index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.slice(1..).get(1), Some(&3));
+    /// assert_eq!(buf.slice(1..).get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&'a T::Item> {
+        match index.checked_sub(self.front.len()) {
+            Some(index) => self.back.get(index),
+            None => self.front.get(index),
+        }
+    }
+
+    /// Returns the pair of slices which contain, in order, the contents of the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.slice(1..).as_slices(), (&[2, 3][..], &[][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&'a [T::Item], &'a [T::Item]) {
+        (self.front, self.back)
+    }
+
+    /// Returns a front-to-back iterator over the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.slice(..2).iter().collect::<Vec<_>>(), [&1, &2]);
+    /// ```
+    pub fn iter(&self) -> iter::Chain<slice::Iter<'a, T::Item>, slice::Iter<'a, T::Item>> {
+        self.front.iter().chain(self.back.iter())
+    }
+}
+
+impl<'a, T: 'a> Clone for RingView<'a, T>
+where
+    T: Array,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: 'a> Copy for RingView<'a, T> where T: Array {}
+
+impl<'a, T: 'a> fmt::Debug for RingView<'a, T>
+where
+    T: Array,
+    T::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for RingView<'a, T>
+where
+    T: Array,
+{
+    type Item = &'a T::Item;
+    type IntoIter = iter::Chain<slice::Iter<'a, T::Item>, slice::Iter<'a, T::Item>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A mutable view of one of the two halves of a `FixedVecDeque`.
+///
+/// This `struct` is created by the [`split_at_mut`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`split_at_mut`]: struct.FixedVecDeque.html#method.split_at_mut
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+pub struct SplitSliceMut<'a, T: 'a>
+where
+    T: Array,
+{
+    iter: IterMut<'a, T>,
+}
+
+impl<'a, T: 'a> SplitSliceMut<'a, T>
+where
+    T: Array,
+{
+    /// Returns the number of elements in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// let (left, right) = buf.split_at_mut(2);
+    /// assert_eq!(left.len(), 2);
+    /// assert_eq!(right.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.iter.len
+    }
+
+    /// Returns `true` if the view is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// let (left, right) = buf.split_at_mut(3);
+    /// assert!(!left.is_empty());
+    /// assert!(right.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.iter.len == 0
+    }
+
+    /// Returns a front-to-back iterator over the elements in the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// let (left, _) = buf.split_at_mut(2);
+    /// assert_eq!(left.iter().collect::<Vec<_>>(), [&1, &2]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            data: self.iter.data,
+            head: self.iter.head,
+            len: self.iter.len,
+            marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references to the elements in the
+    /// view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// let (_, mut right) = buf.split_at_mut(1);
+    ///
+    /// for v in right.iter_mut() {
+    ///     *v *= 10;
+    /// }
+    ///
+    /// assert_eq!(buf, [1, 20, 30]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            data: self.iter.data,
+            head: self.iter.head,
+            len: self.iter.len,
+            marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a> fmt::Debug for SplitSliceMut<'a, T>
+where
+    T: Array,
+    T::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for SplitSliceMut<'a, T>
+where
+    T: Array,
+{
+    type Item = &'a mut T::Item;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+}
+
+/// An owned copy of the elements of a `FixedVecDeque`, front to back.
+///
+/// This `struct` is created by the [`snapshot`] method on [`FixedVecDeque`]. See its
+/// documentation for more.
+///
+/// [`snapshot`]: struct.FixedVecDeque.html#method.snapshot
+/// [`FixedVecDeque`]: struct.FixedVecDeque.html
+#[derive(Clone)]
+pub struct Snapshot<T> {
+    data: Box<[T]>,
+}
+
+impl<T> Snapshot<T> {
+    /// Returns the number of elements in the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.snapshot().len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the snapshot is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let buf = FixedVecDeque::<[u32; 4]>::new();
+    /// assert!(buf.snapshot().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the elements of the snapshot as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 2]>::new();
+    /// buf.extend([1, 2, 3]);
+    /// assert_eq!(buf.snapshot().as_slice(), &[2, 3]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns a front-to-back iterator over the elements of the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 4]>::new();
+    /// buf.extend([1, 2, 3]);
+    ///
+    /// let snapshot = buf.snapshot();
+    /// assert_eq!(snapshot.iter().rev().collect::<Vec<_>>(), [&3, &2, &1]);
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<T> fmt::Debug for Snapshot<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> IntoIterator for Snapshot<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_vec().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Snapshot<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for &'a FixedVecDeque<T>
+where
+    T: Array,
+{
+    type Item = &'a T::Item;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<A> Extend<A::Item> for FixedVecDeque<A>
+where
+    A: Array,
+{
+    fn extend<T: IntoIterator<Item = A::Item>>(&mut self, iter: T) {
+        for elt in iter {
+            *self.push_back() = elt;
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Extend<&'a T> for FixedVecDeque<[T; N]>
+where
+    T: 'a + Copy,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for elt in iter {
+            *self.push_back() = *elt;
+        }
+    }
+}
+
+impl<T> fmt::Debug for FixedVecDeque<T>
+where
+    T: Array,
+    T::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+/// Writing appends the UTF-8 encoded string to the back of the `FixedVecDeque` through
+/// [`push_back`], overwriting the oldest bytes if it is full.
+///
+/// Note that overwriting might leave a partial UTF-8 sequence at the front.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_vec_deque::FixedVecDeque;
+/// use std::fmt::Write;
+///
+/// let mut buf = FixedVecDeque::<[u8; 8]>::new();
+/// write!(buf, "{}-{}", 12, 34).unwrap();
+/// assert_eq!(buf, *b"12-34");
+///
+/// write!(buf, "{}", 5678).unwrap();
+/// assert_eq!(buf, *b"2-345678");
+/// ```
+///
+/// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+impl<const N: usize> fmt::Write for FixedVecDeque<[u8; N]> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend(s.bytes());
+        Ok(())
+    }
+}
+
+/// Writing appends the characters of the string to the back of the `FixedVecDeque` through
+/// [`push_back`], overwriting the oldest characters if it is full.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_vec_deque::FixedVecDeque;
+/// use std::fmt::Write;
+///
+/// let mut buf = FixedVecDeque::<[char; 4]>::new();
+/// write!(buf, "ö{}", 1).unwrap();
+/// assert_eq!(buf, ['ö', '1']);
+///
+/// write!(buf, "{}", 234).unwrap();
+/// assert_eq!(buf, ['1', '2', '3', '4']);
+/// ```
+///
+/// [`push_back`]: struct.FixedVecDeque.html#method.push_back
+impl<const N: usize> fmt::Write for FixedVecDeque<[char; N]> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend(s.chars());
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        *self.push_back() = c;
+        Ok(())
+    }
+}
+
+impl<A> FromIterator<A::Item> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: Default,
+{
+    fn from_iter<T: IntoIterator<Item = A::Item>>(iter: T) -> FixedVecDeque<A> {
+        let mut deq = FixedVecDeque::new();
+        deq.extend(iter);
+        deq
+    }
+}
+
+impl<A> TryFrom<Vec<A::Item>> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: Default,
+{
+    type Error = CapacityError;
+
+    /// Construct a `FixedVecDeque` by moving the elements out of a vector.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the vector contains more elements than the `FixedVecDeque` can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let buf = FixedVecDeque::<[u32; 4]>::try_from(vec![1, 2, 3]).unwrap();
+    /// assert_eq!(buf, [1, 2, 3]);
+    ///
+    /// assert!(FixedVecDeque::<[u32; 2]>::try_from(vec![1, 2, 3]).is_err());
+    /// ```
+    fn try_from(vec: Vec<A::Item>) -> Result<Self, Self::Error> {
+        if vec.len() > A::size() {
+            return Err(CapacityError::new(A::size()));
+        }
+
+        let mut deq = FixedVecDeque::new();
+        deq.extend(vec);
+        Ok(deq)
+    }
+}
+
+impl<A> TryFrom<&[A::Item]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: Default + Clone,
+{
+    type Error = CapacityError;
+
+    /// Construct a `FixedVecDeque` by cloning the elements of a slice.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the slice contains more elements than the `FixedVecDeque` can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let buf = FixedVecDeque::<[u32; 4]>::try_from(&[1, 2, 3][..]).unwrap();
+    /// assert_eq!(buf, [1, 2, 3]);
+    ///
+    /// assert!(FixedVecDeque::<[u32; 2]>::try_from(&[1, 2, 3][..]).is_err());
+    /// ```
+    fn try_from(slice: &[A::Item]) -> Result<Self, Self::Error> {
+        if slice.len() > A::size() {
+            return Err(CapacityError::new(A::size()));
+        }
+
+        let mut deq = FixedVecDeque::new();
+        deq.extend(slice.iter().cloned());
+        Ok(deq)
+    }
+}
+
+impl<A> From<FixedVecDeque<A>> for VecDeque<A::Item>
+where
+    A: Array,
+    A::Item: Default,
+{
+    /// Convert a `FixedVecDeque` into a heap-allocated `VecDeque` by moving out its live
+    /// elements, front to back.
+    ///
+    /// Like [`into_vec`], the elements are moved out by replacing them with their default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = FixedVecDeque::<[u32; 3]>::new();
+    /// buf.extend([1, 2, 3, 4]);
+    ///
+    /// let mut deq = VecDeque::from(buf);
+    /// deq.push_back(5);
+    /// assert_eq!(deq, [2, 3, 4, 5]);
+    /// ```
+    ///
+    /// [`into_vec`]: struct.FixedVecDeque.html#method.into_vec
+    fn from(deq: FixedVecDeque<A>) -> Self {
+        deq.into_vec().into()
+    }
+}
+
+/// Error raised when more elements are provided than a `FixedVecDeque` can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    capacity: usize,
+}
+
+impl CapacityError {
+    #[inline]
+    fn new(capacity: usize) -> Self {
+        CapacityError { capacity }
+    }
+
+    /// The capacity of the `FixedVecDeque` that was exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::FixedVecDeque;
+    ///
+    /// let error = FixedVecDeque::<[u32; 2]>::try_from(vec![1, 2, 3]).unwrap_err();
+    /// assert_eq!(error.capacity(), 2);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "capacity of {} exceeded", self.capacity)
+    }
+}
+
+impl error::Error for CapacityError {}
+
+/// Types that can be used as the backing store for a FixedVecDeque.
+///
+/// This is implemented for arrays, and for [`BoxedArray`] which stores its elements on the heap.
+///
+/// # Safety
+///
+/// Implementors must uphold the following, which `FixedVecDeque` relies on for memory safety:
+///
+/// * `SIZE` is the number of elements stored, and [`size`] must return `SIZE`.
+/// * [`ptr`] and [`ptr_mut`] must return a pointer to `SIZE` contiguous and initialized elements,
+///   which is valid for reads (and for writes through [`ptr_mut`]) for as long as the borrow it
+///   was created from. Both must point to the same elements, and must keep doing so for as long
+///   as the value is not moved.
+/// * [`wrap_add`] and [`wrap_sub`] must return an index less than `SIZE` if `SIZE` is non-zero.
+/// * [`from_fn`] must return a value where every element is initialized. Its default
+///   implementation writes the elements through [`ptr_mut`] into an uninitialized value, which
+///   is only correct if the elements are stored inline in `Self`. Any other implementor must
+///   override it.
+///
+/// [`BoxedArray`]: struct.BoxedArray.html
+/// [`size`]: trait.Array.html#method.size
+/// [`ptr`]: trait.Array.html#tymethod.ptr
+/// [`ptr_mut`]: trait.Array.html#tymethod.ptr_mut
+/// [`wrap_add`]: trait.Array.html#method.wrap_add
+/// [`wrap_sub`]: trait.Array.html#method.wrap_sub
+/// [`from_fn`]: trait.Array.html#method.from_fn
+pub unsafe trait Array {
+    /// The type of the array's elements.
+    type Item;
+
+    /// The number of items the array can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_vec_deque::Array;
+    ///
+    /// const N: usize = <[u8; 16] as Array>::SIZE;
+    /// let buf = [0u32; N];
+    /// assert_eq!(buf.len(), 16);
+    /// ```
+    const SIZE: usize;
+
+    /// Returns the number of items the array can hold.
+    #[inline]
+    fn size() -> usize {
+        Self::SIZE
+    }
+
+    /// Returns a pointer to the first element of the array.
+    fn ptr(&self) -> *const Self::Item;
+
+    /// Returns a mutable pointer to the first element of the array.
+    fn ptr_mut(&mut self) -> *mut Self::Item;
+
+    /// Constructs the array by calling `f` with the index of each element, in order.
+    ///
+    /// If `f` panics, the elements constructed so far are dropped.
+    fn from_fn<F>(mut f: F) -> Self
+    where
+        Self: Sized,
+        F: FnMut(usize) -> Self::Item,
+    {
+        /// Drops the elements initialized so far if `f` panics.
+        struct Guard<I> {
+            ptr: *mut I,
+            len: usize,
+        }
+
+        impl<I> Drop for Guard<I> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, self.len));
+                }
+            }
+        }
+
+        unsafe {
+            let mut data: mem::MaybeUninit<Self> = mem::MaybeUninit::uninit();
+            let m = (*data.as_mut_ptr()).ptr_mut();
+
+            let mut guard = Guard { ptr: m, len: 0 };
+
+            for o in 0..Self::size() {
+                ptr::write(m.add(o), f(o));
+                guard.len += 1;
+            }
+
+            mem::forget(guard);
+            data.assume_init()
+        }
+    }
+
+    /// Returns the index in the underlying buffer for a given logical element
+    /// index + addend.
+    ///
+    /// This doesn't overflow, even if `idx + addend` is greater than `usize::MAX`.
+    ///
+    /// For zero-sized arrays this always returns 0.
+    #[inline]
+    fn wrap_add(idx: usize, addend: usize) -> usize {
+        let size = Self::size();
+
+        if size == 0 {
+            return 0;
+        }
+
+        match idx.checked_add(addend) {
+            Some(sum) => sum % size,
+            None => {
+                // NB: reduce both operands first, so that the sum can't overflow.
+                let (idx, addend) = (idx % size, addend % size);
+                let to_end = size - idx;
+
+                if addend >= to_end {
+                    addend - to_end
+                } else {
+                    idx + addend
+                }
+            }
+        }
+    }
+
+    /// Returns the index in the underlying buffer for a given logical element
+    /// index - subtrahend.
+    ///
+    /// This doesn't underflow, even if `subtrahend` is greater than `idx` by more than the
+    /// size of the array.
+    ///
+    /// For zero-sized arrays this always returns 0.
+    #[inline]
+    fn wrap_sub(idx: usize, subtrahend: usize) -> usize {
+        let size = Self::size();
+
+        if size == 0 {
+            return 0;
+        }
+
+        if subtrahend <= idx {
+            return idx - subtrahend;
+        }
+
+        let rem = subtrahend - idx;
+
+        if rem <= size {
+            size - rem
+        } else {
+            (size - rem % size) % size
+        }
+    }
+}
+
+unsafe impl<const N: usize, T> Array for [T; N] {
+    type Item = T;
+
+    const SIZE: usize = N;
+
+    #[inline]
+    fn ptr(&self) -> *const Self::Item {
+        self.as_ptr()
+    }
+
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut Self::Item {
+        self.as_mut_ptr()
+    }
+}
+
+unsafe impl<const N: usize, T> Array for &mut [T; N] {
+    type Item = T;
+
+    const SIZE: usize = N;
+
+    #[inline]
+    fn ptr(&self) -> *const Self::Item {
+        self.as_ptr()
+    }
+
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut Self::Item {
+        self.as_mut_ptr()
+    }
+
+    fn from_fn<F>(_: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Item,
+    {
+        panic!("a borrowed array cannot be constructed")
+    }
+}
+
+/// A heap-allocated backing store for a `FixedVecDeque` holding `N` elements.
+///
+/// This is useful for large capacities, since it avoids placing the whole buffer on the stack
+/// and makes moving the `FixedVecDeque` cheap.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_vec_deque::{BoxedArray, FixedVecDeque};
+///
+/// let mut buf = FixedVecDeque::<BoxedArray<u64, 0x100000>>::new();
+/// assert_eq!(buf.capacity(), 0x100000);
+///
+/// buf.extend(0..0x100001);
+/// assert_eq!(buf.front(), Some(&1));
+/// assert_eq!(buf.back(), Some(&0x100000));
+/// ```
+pub struct BoxedArray<T, const N: usize> {
+    data: Box<[T]>,
+}
+
+unsafe impl<const N: usize, T> Array for BoxedArray<T, N> {
+    type Item = T;
+
+    const SIZE: usize = N;
+
+    #[inline]
+    fn ptr(&self) -> *const Self::Item {
+        self.data.as_ptr()
+    }
+
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut Self::Item {
+        self.data.as_mut_ptr()
+    }
+
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Item,
+    {
+        // NB: the elements are stored on the heap, so they can't be initialized in place through
+        // the default implementation.
+        let data = (0..N).map(f).collect::<Vec<_>>().into_boxed_slice();
+        debug_assert_eq!(data.len(), N);
+        BoxedArray { data }
+    }
+}
+
+impl<A> Eq for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: Eq,
+{
+}
+
+impl<A, B> PartialEq<FixedVecDeque<B>> for FixedVecDeque<A>
+where
+    A: Array,
+    B: Array,
+    A::Item: PartialEq<B::Item>,
+{
+    fn eq(&self, other: &FixedVecDeque<B>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let (sa, sb) = self.as_slices();
+        let (oa, ob) = other.as_slices();
+        ring_slices_eq(sa, sb, oa, ob)
+    }
+}
+
+impl<A, B> PartialEq<VecDeque<B>> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &VecDeque<B>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let (sa, sb) = self.as_slices();
+        let (oa, ob) = other.as_slices();
+        ring_slices_eq(sa, sb, oa, ob)
+    }
+}
+
+/// Compare two sequences, each made up of a pair of slices with the same combined length.
+fn ring_slices_eq<A, B>(sa: &[A], sb: &[A], oa: &[B], ob: &[B]) -> bool
+where
+    A: PartialEq<B>,
+{
+    debug_assert_eq!(sa.len() + sb.len(), oa.len() + ob.len());
+
+    match sa.len().cmp(&oa.len()) {
+        cmp::Ordering::Less => {
+            // Always divisible in three sections, for example:
+            // self:  [a b c|d e f]
+            // other: [0 1 2 3|4 5]
+            // front = 3, mid = 1,
+            // [a b c] == [0 1 2] && [d] == [3] && [e f] == [4 5]
+            let front = sa.len();
+            let mid = oa.len() - front;
+
+            let (oa_front, oa_mid) = oa.split_at(front);
+            let (sb_mid, sb_back) = sb.split_at(mid);
+            debug_assert_eq!(sa.len(), oa_front.len());
+            debug_assert_eq!(sb_mid.len(), oa_mid.len());
+            debug_assert_eq!(sb_back.len(), ob.len());
+            sa == oa_front && sb_mid == oa_mid && sb_back == ob
+        }
+        cmp::Ordering::Equal => sa == oa && sb == ob,
+        cmp::Ordering::Greater => {
+            let front = oa.len();
+            let mid = sa.len() - front;
+
+            let (sa_front, sa_mid) = sa.split_at(front);
+            let (ob_mid, ob_back) = ob.split_at(mid);
+            debug_assert_eq!(sa_front.len(), oa.len());
+            debug_assert_eq!(sa_mid.len(), ob_mid.len());
+            debug_assert_eq!(sb.len(), ob_back.len());
+            sa_front == oa && sa_mid == ob_mid && sb == ob_back
+        }
+    }
+}
+
+macro_rules! impl_slice_eq {
+    ($self:ident, $other:ident) => {
+        if $self.len() != $other.len() {
+            return false;
+        } else {
+            let (sa, sb) = $self.as_slices();
+            let (oa, ob) = $other[..].split_at(sa.len());
+            sa == oa && sb == ob
+        }
+    };
+}
+
+impl<A, B> PartialEq<[B]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &[B]) -> bool {
+        impl_slice_eq!(self, other)
+    }
+}
+
+impl<A, B> PartialEq<&[B]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &&[B]) -> bool {
+        impl_slice_eq!(self, other)
+    }
+}
+
+impl<A, B> PartialEq<&mut [B]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &&mut [B]) -> bool {
+        impl_slice_eq!(self, other)
+    }
+}
+
+impl<const N: usize, A, B> PartialEq<[B; N]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &[B; N]) -> bool {
+        impl_slice_eq!(self, other)
+    }
+}
+
+impl<const N: usize, A, B> PartialEq<&[B; N]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &&[B; N]) -> bool {
+        impl_slice_eq!(self, other)
+    }
+}
+
+impl<const N: usize, A, B> PartialEq<&mut [B; N]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &&mut [B; N]) -> bool {
+        impl_slice_eq!(self, other)
+    }
+}
+
+impl<A, B> PartialEq<Vec<B>> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialEq<B>,
+{
+    fn eq(&self, other: &Vec<B>) -> bool {
+        impl_slice_eq!(self, other)
+    }
+}
+
+impl<A> PartialOrd for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &FixedVecDeque<A>) -> Option<cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, B> PartialOrd<[B]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialOrd<B>,
+{
+    fn partial_cmp(&self, other: &[B]) -> Option<cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, B> PartialOrd<&[B]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialOrd<B>,
+{
+    fn partial_cmp(&self, other: &&[B]) -> Option<cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<const N: usize, A, B> PartialOrd<[B; N]> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialOrd<B>,
+{
+    fn partial_cmp(&self, other: &[B; N]) -> Option<cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, B> PartialOrd<Vec<B>> for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: PartialOrd<B>,
+{
+    fn partial_cmp(&self, other: &Vec<B>) -> Option<cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A> Ord for FixedVecDeque<A>
+where
+    A: Array,
+    A::Item: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &FixedVecDeque<A>) -> cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Returns the two slices that cover the `FixedVecDeque`'s valid range
+trait RingSlices: Sized {
+    fn slice(self, from: usize, to: usize) -> Self;
+    fn split_at(self, i: usize) -> (Self, Self);
+
+    fn ring_slices(buf: Self, head: usize, tail: usize) -> (Self, Self) {
+        let contiguous = tail <= head;
+        if contiguous {
+            let (empty, buf) = buf.split_at(0);
+            (buf.slice(tail, head), empty)
+        } else {
+            let (mid, right) = buf.split_at(tail);
+            let (left, _) = mid.split_at(head);
+            (right, left)
+        }
+    }
+}
+
+impl<T> RingSlices for &[T] {
+    fn slice(self, from: usize, to: usize) -> Self {
+        &self[from..to]
+    }
+
+    fn split_at(self, i: usize) -> (Self, Self) {
+        (*self).split_at(i)
+    }
+}
+
+impl<T> RingSlices for &mut [T] {
+    fn slice(self, from: usize, to: usize) -> Self {
+        &mut self[from..to]
+    }
+
+    fn split_at(self, i: usize) -> (Self, Self) {
+        (*self).split_at_mut(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Array, FixedVecDeque};
+    use std::cell::Cell;
+    use std::cmp;
+    use std::mem;
+    use std::rc::Rc;
+
+    /// Construct a new and verify that its size is the sum of all it's elements.
+    fn test_new<T>() -> FixedVecDeque<T>
+    where
+        T: Array,
+        T::Item: Default,
+    {
+        let fixed = FixedVecDeque::<T>::new();
+
+        assert_eq!(
+            mem::size_of::<T::Item>() * 4 + mem::size_of::<FixedVecDeque<[Zero; 1]>>(),
+            mem::size_of::<FixedVecDeque<[T::Item; 4]>>()
+        );
+
+        #[derive(Debug, Default, PartialEq, Eq)]
+        struct Zero {}
+
+        fixed
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut fixed = test_new::<[Foo; 4]>();
+
+        #[derive(Debug, Default, PartialEq, Eq)]
+        struct Foo {
+            data: u64,
+        }
+
+        fixed.push_back().data = 1;
+        fixed.push_back().data = 2;
+
+        assert_eq!(Some(&mut Foo { data: 1 }), fixed.pop_front());
+        assert_eq!(Some(&mut Foo { data: 2 }), fixed.pop_front());
+        assert_eq!(None, fixed.pop_front());
+    }
+
+    // make sure that we correctly ported the various functions, since they depended on sizes being
+    // aligned to a power of two.
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_unaligned_sizes() {
+        macro_rules! test_size {
+            ($size:expr) => {
+                let mut buf = FixedVecDeque::<[u32; $size]>::new();
+
+                assert_eq!(buf.back(), None);
+                assert_eq!(buf.front(), None);
+                assert_eq!(buf.get(0), None);
+                assert_eq!(buf.get_mut(0), None);
+
+                for i in 1..($size + 1) {
+                    *buf.push_back() = i;
+
+                    assert_eq!(buf.front(), Some(&1));
+                    assert_eq!(buf.back(), Some(&i));
+                    assert_eq!(buf.get(0), Some(&1));
+                    assert_eq!(buf.get(buf.len() - 1), Some(&i));
+                    assert_eq!(buf[0], 1);
+                    assert_eq!(buf[buf.len() - 1], i);
+                }
+
+                let mut buf = FixedVecDeque::<[u32; $size]>::new();
+
+                assert_eq!(buf.back(), None);
+                assert_eq!(buf.front(), None);
+                assert_eq!(buf.get(0), None);
+                assert_eq!(buf.get_mut(0), None);
+
+                for i in 1..($size + 1) {
+                    *buf.push_front() = i;
+
+                    assert_eq!(buf.back(), Some(&1));
+                    assert_eq!(buf.front(), Some(&i));
+                    assert_eq!(buf.get(buf.len() - 1), Some(&1));
+                    assert_eq!(buf.get(0), Some(&i));
+                    assert_eq!(buf[buf.len() - 1], 1);
+                    assert_eq!(buf[0], i);
+                }
+            };
+        }
+
+        test_size!(0);
+        test_size!(1);
+        test_size!(2);
+        test_size!(3);
+        test_size!(4);
+        test_size!(5);
+        test_size!(6);
+        test_size!(7);
+        test_size!(8);
+        test_size!(9);
+        test_size!(10);
+        test_size!(11);
+        test_size!(12);
+        test_size!(13);
+        test_size!(14);
+        test_size!(15);
+        test_size!(16);
+        test_size!(20);
+        test_size!(24);
+        test_size!(32);
+        test_size!(36);
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut a = 0;
+        let mut b = 0;
+        let mut c = 0;
+
+        {
+            let mut fixed = FixedVecDeque::<[Foo; 2]>::new();
+            fixed.push_back().value = Some(&mut a);
+            fixed.push_back().value = Some(&mut b);
+            fixed.push_back().value = Some(&mut c);
+        }
+
+        // NB: zero because it will have been overwritten due to the circular nature of the buffer.
+        assert_eq!(a, 0);
+        assert_eq!(b, 1);
+        assert_eq!(c, 1);
+
+        #[derive(Default)]
+        struct Foo<'a> {
+            value: Option<&'a mut u32>,
+        }
+
+        impl Drop for Foo<'_> {
+            fn drop(&mut self) {
+                if let Some(v) = self.value.take() {
+                    *v += 1;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut deq = FixedVecDeque::<[u32; 4]>::new();
+        deq.extend([1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert!(!deq.is_empty());
+        assert!(deq.is_full());
+        assert_eq!(deq.iter().collect::<Vec<_>>(), vec![&5, &6, &7, &8]);
+    }
+
+    #[test]
+    fn test_drain() {
+        let count = Cell::new(0);
+
+        {
+            let mut fixed = FixedVecDeque::<[Foo; 4]>::new();
+            fixed.push_back().value = Some(&count);
+            fixed.push_back().value = Some(&count);
+            fixed.push_back().value = Some(&count);
+
+            let drained = fixed.drain().collect::<Vec<_>>();
+            assert_eq!(drained.len(), 3);
+            assert!(fixed.is_empty());
+            assert_eq!(count.get(), 0);
+
+            drop(drained);
+            assert_eq!(count.get(), 3);
+        }
+
+        // NB: the drained slots have been restored to their default.
+        assert_eq!(count.get(), 3);
+
+        #[derive(Default)]
+        struct Foo<'a> {
+            value: Option<&'a Cell<u32>>,
+        }
+
+        impl Drop for Foo<'_> {
+            fn drop(&mut self) {
+                if let Some(v) = self.value.take() {
+                    v.set(v.get() + 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_panicking_default() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        thread_local! {
+            static PANIC_AT: Cell<usize> = const { Cell::new(usize::MAX) };
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+            static LIVE: Cell<usize> = const { Cell::new(0) };
+        }
+
+        struct Flaky(u32);
+
+        impl Default for Flaky {
+            fn default() -> Self {
+                let calls = CALLS.with(|c| c.replace(c.get() + 1));
+
+                if calls == PANIC_AT.with(Cell::get) {
+                    panic!("default called {} times", calls);
+                }
+
+                LIVE.with(|c| c.set(c.get() + 1));
+                Flaky(0)
+            }
+        }
+
+        impl Drop for Flaky {
+            fn drop(&mut self) {
+                LIVE.with(|c| c.set(c.get() - 1));
+            }
+        }
+
+        fn reset(panic_at: usize) {
+            CALLS.with(|c| c.set(0));
+            PANIC_AT.with(|c| c.set(panic_at));
+        }
+
+        let live = || LIVE.with(Cell::get);
+
+        // panicking while initializing the buffer drops the elements initialized so far.
+        reset(2);
+        assert!(panic::catch_unwind(FixedVecDeque::<[Flaky; 4]>::new).is_err());
+        assert_eq!(live(), 0);
+
+        reset(usize::MAX);
+        let mut deq = FixedVecDeque::<[Flaky; 4]>::new();
+        assert_eq!(live(), 4);
+
+        for v in 0..6 {
+            push(&mut deq, v, false);
+            push(&mut deq, v, true);
+        }
+
+        // panicking while taking elements out leaves the buffer intact.
+        reset(1);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| deq.drain().count()));
+        assert!(result.is_err());
+        assert_eq!(live(), 4);
+
+        reset(2);
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| deq.reset())).is_err());
+        assert_eq!(live(), 4);
+
+        reset(usize::MAX);
+
+        assert!(deq.is_empty());
+
+        for v in 0..6 {
+            push(&mut deq, v, false);
+        }
+
+        push(&mut deq, 6, true);
+
+        assert!(deq.is_full());
+        assert_eq!(
+            deq.iter().map(|v| v.0).collect::<Vec<_>>(),
+            vec![6, 2, 3, 4]
+        );
+
+        drop(deq);
+        assert_eq!(live(), 0);
+
+        fn push(deq: &mut FixedVecDeque<[Flaky; 4]>, value: u32, front: bool) {
+            let slot = if front {
+                deq.push_front()
+            } else {
+                deq.push_back()
+            };
+
+            slot.0 = value;
+        }
+    }
+
+    #[test]
+    fn test_drain_while_front() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+
+        for head in 0..5 {
+            for k in 0..=5 {
+                tester.head = head;
+                tester.len = 0;
+                tester.extend([1, 2, 3, 4, 5]);
+
+                let drained = tester.drain_while_front(|v| *v <= k).collect::<Vec<_>>();
+                assert_eq!(drained, (1..=k).collect::<Vec<_>>());
+                assert_eq!(tester, (k + 1..=5).collect::<Vec<_>>());
+            }
+        }
+
+        // stops at the first element which doesn't match, and stays stopped.
+        tester.head = 3;
+        tester.len = 0;
+        tester.extend([1, 2, 3, 4, 5]);
+        let mut calls = 0;
+
+        {
+            let mut it = tester.drain_while_front(|v| {
+                calls += 1;
+                *v != 2
+            });
+            assert_eq!(it.next(), Some(1));
+            assert_eq!(it.next(), None);
+            assert_eq!(it.next(), None);
+        }
+
+        assert_eq!(calls, 2);
+        assert_eq!(tester, [2, 3, 4, 5]);
+
+        // dropping the iterator early stops draining.
+        {
+            let mut it = tester.drain_while_front(|_| true);
+            assert_eq!(it.size_hint(), (0, Some(4)));
+            assert_eq!(it.next(), Some(2));
+        }
+
+        assert_eq!(tester, [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_drain_early_drop() {
+        let mut fixed = FixedVecDeque::<[Rc<()>; 4]>::new();
+        let value = Rc::new(());
+
+        for _ in 0..6 {
+            *fixed.push_back() = value.clone();
+        }
+
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        let mut drain = fixed.drain();
+        assert!(drain.next().is_some());
+        drop(drain);
+
+        assert!(fixed.is_empty());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+        tester.head = 3;
+        tester.extend([1, 2, 3, 4]);
+        assert_eq!(tester.as_slices(), (&[1, 2][..], &[3, 4][..]));
+
+        assert_eq!(tester.range(..).copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+        assert_eq!(tester.range(1..3).copied().collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(tester.range(2..).copied().collect::<Vec<_>>(), [3, 4]);
+        assert_eq!(tester.range(1..=3).copied().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(tester.range(..=0).copied().collect::<Vec<_>>(), [1]);
+        assert_eq!(tester.range(4..).count(), 0);
+
+        for v in tester.range_mut(1..=2) {
+            *v *= 10;
+        }
+
+        assert_eq!(tester, [1, 20, 30, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.extend([1, 2, 3]);
+        buf.range(1..4);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let mut tester = FixedVecDeque::<[(u32, bool); 6]>::new();
+        tester.head = 4;
+        tester.extend([(1, true), (2, false), (3, true), (4, false), (5, true)]);
+
+        tester.retain_mut(|v| {
+            v.0 *= 10;
+            v.1
+        });
+
+        assert_eq!(tester, [(10, true), (30, true), (50, true)]);
+    }
+
+    #[test]
+    fn test_retain_order() {
+        let mut buf = FixedVecDeque::<[u32; 8]>::new();
+        buf.extend([1, 2, 3, 4, 5]);
+        let mut keep = [true, false, true, false, true].into_iter();
+        buf.retain(|_| keep.next().unwrap());
+        assert_eq!(buf, [1, 3, 5]);
+
+        // exhaustively test every deletion pattern for every tail position.
+        let mut tester = FixedVecDeque::<[u32; 8]>::new();
+        let cap = tester.capacity();
+
+        for len in 0..=cap {
+            for pattern in 0u32..(1 << len) {
+                for tail_pos in 0..cap {
+                    tester.head = tail_pos;
+                    tester.len = 0;
+                    tester.extend(0..len as u32);
+
+                    tester.retain(|&v| pattern & (1 << v) != 0);
+
+                    let expected = (0..len as u32)
+                        .filter(|&v| pattern & (1 << v) != 0)
+                        .collect::<Vec<_>>();
+                    assert_eq!(tester, expected[..]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend_front() {
+        let mut deq = FixedVecDeque::<[u32; 5]>::new();
+        deq.extend_front([1, 2, 3]);
+
+        assert_eq!(deq.len(), 3);
+        assert!(!deq.is_full());
+        assert_eq!(deq.front(), Some(&3));
+        assert_eq!(deq.back(), Some(&1));
+        assert_eq!(deq.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+    }
+
+    #[test]
+    fn test_collect() {
+        let deq: FixedVecDeque<[u32; 4]> = vec![1, 2, 3, 4, 5, 6, 7, 8].into_iter().collect();
+
+        assert!(!deq.is_empty());
+        assert!(deq.is_full());
+        assert_eq!(deq.iter().collect::<Vec<_>>(), vec![&5, &6, &7, &8]);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let deq = FixedVecDeque::<[u32; 4]>::try_from(vec![1, 2, 3, 4]).unwrap();
+        assert!(deq.is_full());
+        assert_eq!(deq, [1, 2, 3, 4]);
+
+        let deq = FixedVecDeque::<[u32; 4]>::try_from(&[1, 2, 3, 4][..]).unwrap();
+        assert!(deq.is_full());
+        assert_eq!(deq, [1, 2, 3, 4]);
+
+        let error = FixedVecDeque::<[u32; 4]>::try_from(vec![1, 2, 3, 4, 5]).unwrap_err();
+        assert_eq!(error.capacity(), 4);
+        assert_eq!(error.to_string(), "capacity of 4 exceeded");
+
+        let error = FixedVecDeque::<[u32; 4]>::try_from(&[1, 2, 3, 4, 5][..]).unwrap_err();
+        assert_eq!(error.capacity(), 4);
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let mut tester = FixedVecDeque::<[String; 5]>::new();
+        tester.head = 3;
+
+        for s in ["a", "b", "c", "d"] {
+            tester.push_back().push_str(s);
+        }
+
+        assert_eq!(tester.as_slices().1.len(), 2);
+
+        let vec = tester.to_vec();
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec, ["a", "b", "c", "d"]);
+
+        let vec = tester.into_vec();
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec, ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_as_contiguous_slice() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+        assert_eq!(tester.as_contiguous_slice(), Some(&[][..]));
+
+        tester.extend([1, 2]);
+        assert_eq!(tester.as_contiguous_slice(), Some(&[1, 2][..]));
+
+        tester.extend([3, 4]);
+        assert!(tester.is_full());
+        assert_eq!(tester.as_contiguous_slice(), Some(&[1, 2, 3, 4][..]));
+
+        *tester.push_back() = 5;
+        assert!(tester.is_full());
+        assert_eq!(tester.as_contiguous_slice(), None);
+
+        tester.head = 3;
+        tester.len = 0;
+        tester.extend([1, 2]);
+        assert_eq!(tester.as_contiguous_slice(), None);
+        tester.pop_front();
+        assert_eq!(tester.as_contiguous_slice(), Some(&[2][..]));
+    }
+
+    #[test]
+    fn test_pop_if() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+        assert_eq!(tester.pop_front_if(|_| true), None);
+        assert_eq!(tester.pop_back_if(|_| true), None);
+
+        tester.extend([1, 2, 3, 4, 5]);
+        assert_eq!(tester.pop_front_if(|&v| v == 2), Some(&mut 2));
+        assert_eq!(tester.pop_front_if(|&v| v == 2), None);
+        assert_eq!(tester.pop_back_if(|&v| v == 5), Some(&mut 5));
+        assert_eq!(tester.pop_back_if(|&v| v == 5), None);
+        assert_eq!(tester, [3, 4]);
+
+        let mut calls = 0;
+        assert_eq!(
+            tester.pop_front_if(|_| {
+                calls += 1;
+                false
+            }),
+            None
+        );
+        assert_eq!(calls, 1);
+        assert_eq!(tester, [3, 4]);
+    }
+
+    #[test]
+    fn test_clone() {
+        let a: FixedVecDeque<[u32; 4]> = vec![1, 2, 3, 4].into_iter().collect();
+        let b = a.clone();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_clone_strings() {
+        let mut a = FixedVecDeque::<[String; 4]>::new();
+        a.head = 2;
+
+        for s in ["a", "b", "c", "d", "e"] {
+            *a.push_back() = s.to_string();
+        }
+
+        a.pop_front();
+        let mut b = a.clone();
+        assert_eq!(a, b);
+        assert_eq!(b, ["c", "d", "e"]);
+
+        // Stale slots are cloned as well.
+        assert_eq!(b.push_front(), "b");
+        b.front_mut().unwrap().push_str("ee");
+        assert_eq!(b, ["bee", "c", "d", "e"]);
+
+        drop(a);
+        assert_eq!(b, ["bee", "c", "d", "e"]);
+
+        let value = Rc::new(());
+        let c = FixedVecDeque::<[Option<Rc<()>>; 4]>::from_fn(4, |_| Some(value.clone()));
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        {
+            let _d = c.clone();
+            assert_eq!(Rc::strong_count(&value), 9);
+        }
+
+        assert_eq!(Rc::strong_count(&value), 5);
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let mut a = FixedVecDeque::<[String; 4]>::new();
+        a.push_back().push_str("foo");
+
+        let mut b = FixedVecDeque::<[String; 4]>::new();
+        b.head = 3;
+
+        for s in ["a", "b", "c"] {
+            b.push_back().push_str(s);
+        }
+
+        a.clone_from(&b);
+        assert_eq!(a, b);
+        assert_eq!(a, ["a", "b", "c"]);
+        assert_eq!(a.as_slices(), b.as_slices());
+    }
+
+    #[test]
+    fn test_large_array_eq() {
+        let mut array = [0u8; 64];
+
+        for (i, v) in array.iter_mut().enumerate() {
+            *v = i as u8;
+        }
+
+        let mut deq = FixedVecDeque::<[u8; 64]>::new();
+        deq.extend(0..64);
+
+        assert_eq!(deq, array);
+        assert_eq!(deq, &array);
+        assert_eq!(deq, &mut array);
+        assert_eq!(deq, &array[..]);
+        assert_eq!(deq, &mut array[..]);
+
+        *deq.push_back() = 0;
+        assert_ne!(deq, array);
+        assert_ne!(deq, &array);
+        assert_ne!(deq, &array[..]);
+    }
+
+    #[test]
+    fn test_hash_matches_slice() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: ?Sized + Hash>(value: &T) -> u64 {
+            let mut state = DefaultHasher::new();
+            value.hash(&mut state);
+            state.finish()
+        }
+
+        let mut tester = FixedVecDeque::<[u32; 8]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            for len in 0..=tester.capacity() {
+                tester.head = tail_pos;
+                tester.len = 0;
+                tester.extend(0..len as u32);
+
+                let expected = (0..len as u32).collect::<Vec<_>>();
+                assert_eq!(hash(&tester), hash(&expected[..]));
+            }
+        }
+
+        let mut tester = FixedVecDeque::<[String; 4]>::new();
+        tester.head = 3;
+
+        for s in ["a", "b", "c"] {
+            tester.push_back().push_str(s);
+        }
+
+        assert_eq!(hash(&tester), hash(&["a", "b", "c"][..]));
+    }
+
+    #[test]
+    fn test_iter_clone() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+        tester.head = 2;
+        tester.extend([1, 2, 3, 4]);
+
+        let mut it = tester.iter();
+        assert_eq!(it.next(), Some(&1));
+
+        let copy = it;
+        let clone = dup(&it);
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.collect::<Vec<_>>(), [&3, &4]);
+        assert_eq!(copy.collect::<Vec<_>>(), [&2, &3, &4]);
+        assert_eq!(clone.collect::<Vec<_>>(), [&2, &3, &4]);
+
+        fn dup<I: Clone>(it: &I) -> I {
+            it.clone()
+        }
+    }
+
+    #[test]
+    fn test_iter_debug() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+        tester.head = 2;
+        tester.extend([1, 2, 3, 4]);
+
+        let mut it = tester.iter();
+        assert_eq!(format!("{:?}", it), format!("{:?}", [1, 2, 3, 4]));
+        it.next();
+        assert_eq!(format!("{:?}", it), format!("{:?}", [2, 3, 4]));
+        assert_eq!(it.count(), 3);
+
+        let mut it = tester.iter_mut();
+        it.next();
+        it.next();
+        assert_eq!(format!("{:?}", it), format!("{:?}", [3, 4]));
+        assert_eq!(it.count(), 2);
+    }
+
+    #[test]
+    fn test_windows() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+        tester.head = 3;
+        tester.extend([1, 2, 3, 4, 5]);
+        assert_eq!(tester.as_slices(), (&[1, 2][..], &[3, 4, 5][..]));
+
+        let windows = |size| {
+            tester
+                .windows(size)
+                .map(|w| w.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(windows(1), [[1], [2], [3], [4], [5]]);
+        assert_eq!(windows(2), [[1, 2], [2, 3], [3, 4], [4, 5]]);
+        assert_eq!(windows(4), [[1, 2, 3, 4], [2, 3, 4, 5]]);
+        assert_eq!(windows(5), [[1, 2, 3, 4, 5]]);
+        assert!(windows(6).is_empty());
+
+        assert_eq!(tester.windows(2).size_hint(), (4, Some(4)));
+        assert_eq!(tester.windows(6).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_windows_zero() {
+        let buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.windows(0);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+        tester.head = 3;
+        tester.extend([1, 2, 3, 4, 5]);
+        assert_eq!(tester.as_slices(), (&[1, 2][..], &[3, 4, 5][..]));
+
+        let chunks = |size| {
+            tester
+                .chunks(size)
+                .map(|w| w.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(chunks(1), [[1], [2], [3], [4], [5]]);
+        assert_eq!(chunks(2), [&[1, 2][..], &[3, 4][..], &[5][..]]);
+        assert_eq!(chunks(3), [&[1, 2, 3][..], &[4, 5][..]]);
+        assert_eq!(chunks(5), [[1, 2, 3, 4, 5]]);
+        assert_eq!(chunks(6), [[1, 2, 3, 4, 5]]);
+
+        assert_eq!(tester.chunks(2).size_hint(), (3, Some(3)));
+        assert_eq!(tester.chunks(5).size_hint(), (1, Some(1)));
+        assert_eq!(tester.chunks(usize::MAX).size_hint(), (1, Some(1)));
+        assert_eq!(chunks(usize::MAX), [[1, 2, 3, 4, 5]]);
+
+        tester.clear();
+        assert_eq!(tester.chunks(2).count(), 0);
+        assert_eq!(tester.chunks(2).size_hint(), (0, Some(0)));
+        assert_eq!(tester.chunks(usize::MAX).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_zero() {
+        let buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.chunks(0);
+    }
+
+    #[test]
+    fn test_rchunks() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+        tester.head = 3;
+        tester.extend([1, 2, 3, 4, 5]);
+        assert_eq!(tester.as_slices(), (&[1, 2][..], &[3, 4, 5][..]));
+
+        let rchunks = |size| {
+            tester
+                .rchunks(size)
+                .map(|w| w.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(rchunks(1), [[5], [4], [3], [2], [1]]);
+        assert_eq!(rchunks(2), [&[4, 5][..], &[2, 3][..], &[1][..]]);
+        assert_eq!(rchunks(3), [&[3, 4, 5][..], &[1, 2][..]]);
+        assert_eq!(rchunks(4), [&[2, 3, 4, 5][..], &[1][..]]);
+        assert_eq!(rchunks(5), [[1, 2, 3, 4, 5]]);
+        assert_eq!(rchunks(6), [[1, 2, 3, 4, 5]]);
+
+        assert_eq!(tester.rchunks(2).size_hint(), (3, Some(3)));
+        assert_eq!(tester.rchunks(5).size_hint(), (1, Some(1)));
+        assert_eq!(tester.rchunks(usize::MAX).size_hint(), (1, Some(1)));
+        assert_eq!(rchunks(usize::MAX), [[1, 2, 3, 4, 5]]);
+
+        tester.clear();
+        assert_eq!(tester.rchunks(2).count(), 0);
+        assert_eq!(tester.rchunks(2).size_hint(), (0, Some(0)));
+        assert_eq!(tester.rchunks(usize::MAX).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rchunks_zero() {
+        let buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.rchunks(0);
+    }
+
+    #[test]
+    fn test_slice() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+        tester.extend([1, 2, 3, 4]);
+
+        let view = tester.slice(1..3);
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.as_slices(), (&[2, 3][..], &[][..]));
+        assert_eq!(view.into_iter().collect::<Vec<_>>(), [&2, &3]);
+
+        tester.head = 3;
+        tester.len = 0;
+        tester.extend([1, 2, 3, 4]);
+        assert_eq!(tester.as_slices(), (&[1, 2][..], &[3, 4][..]));
+
+        let view = tester.slice(1..3);
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.as_slices(), (&[2][..], &[3][..]));
+        assert_eq!(view.get(0), Some(&2));
+        assert_eq!(view.get(1), Some(&3));
+        assert_eq!(view.get(2), None);
+        assert_eq!(format!("{:?}", view), "[2, 3]");
+        assert_eq!(view.into_iter().collect::<Vec<_>>(), [&2, &3]);
+
+        assert_eq!(tester.slice(..2).as_slices(), (&[1, 2][..], &[][..]));
+        assert_eq!(tester.slice(2..).as_slices(), (&[][..], &[3, 4][..]));
+        assert_eq!(tester.slice(..).len(), 4);
+        assert!(tester.slice(2..2).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.extend([1, 2, 3]);
+        buf.slice(2..4);
+    }
+
+    #[test]
+    fn test_iter_nth() {
+        let mut tester = FixedVecDeque::<[u32; 8]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            tester.head = tail_pos;
+            tester.len = 0;
+            tester.extend(0..6);
+
+            let expected = tester.iter().copied().collect::<Vec<_>>();
+
+            for n in 0..8 {
+                assert_eq!(tester.iter().nth(n), expected.get(n));
+                assert_eq!(
+                    tester.iter_mut().nth(n).map(|v| *v),
+                    expected.get(n).copied()
+                );
+            }
+
+            let mut it = tester.iter();
+            assert_eq!(it.nth(1), Some(&expected[1]));
+            assert_eq!(it.nth(2), Some(&expected[4]));
+            assert_eq!(it.next(), Some(&expected[5]));
+            assert_eq!(it.nth(1), None);
+
+            let mut it = tester.iter_mut();
+            assert_eq!(it.nth(4).map(|v| *v), Some(expected[4]));
+            assert_eq!(it.nth(1), None);
+            assert_eq!(it.next(), None);
+        }
+    }
+
+    #[test]
+    fn test_iter_count_last() {
+        let mut tester = FixedVecDeque::<[u32; 8]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            tester.head = tail_pos;
+            tester.len = 0;
+            tester.extend(0..6);
+
+            assert_eq!(tester.iter().count(), 6);
+            assert_eq!(tester.iter().last(), Some(&5));
+            assert_eq!(tester.iter_mut().count(), 6);
+            assert_eq!(tester.iter_mut().last(), Some(&mut 5));
+
+            let mut it = tester.iter();
+            it.nth(3);
+            assert_eq!(it.count(), 2);
+            assert_eq!(it.last(), Some(&5));
+
+            let mut it = tester.iter_mut();
+            it.nth(4);
+            assert_eq!(it.count(), 1);
+
+            let mut it = tester.iter_mut();
+            it.nth(5);
+            assert_eq!(it.last(), None);
+
+            tester.clear();
+            assert_eq!(tester.iter().count(), 0);
+            assert_eq!(tester.iter().last(), None);
+        }
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut tester = FixedVecDeque::<[String; 4]>::new();
+        tester.head = 3;
+
+        for s in ["a", "b", "c"] {
+            tester.push_back().push_str(s);
+        }
+
+        assert_eq!(tester.as_slices().1.len(), 2);
+        assert_eq!(tester.replace(0, String::from("x")).as_deref(), Some("a"));
+        assert_eq!(tester.replace(2, String::from("z")).as_deref(), Some("c"));
+        assert_eq!(tester.replace(3, String::from("w")), None);
+        assert_eq!(tester, ["x", "b", "z"]);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_par_iter() {
+        use rayon::prelude::*;
+
+        let mut tester = FixedVecDeque::<[u64; 1024]>::new();
+        tester.head = 512;
+        tester.extend(0..1000);
+        assert!(!tester.as_slices().1.is_empty());
+
+        let expected = tester.iter().sum::<u64>();
+        assert_eq!(tester.par_iter().sum::<u64>(), expected);
+
+        tester.par_iter_mut().for_each(|v| *v *= 2);
+        assert_eq!(tester.par_iter().sum::<u64>(), expected * 2);
+        assert!(tester.iter().copied().eq((0..1000).map(|v| v * 2)));
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn test_buf() {
+        use bytes::Buf;
+
+        let mut tester = FixedVecDeque::<[u8; 8]>::new();
+        tester.head = 5;
+        tester.extend(*b"abcdef");
+        assert_eq!(tester.as_slices(), (&b"abc"[..], &b"def"[..]));
+
+        assert_eq!(tester.remaining(), 6);
+        assert_eq!(tester.chunk(), b"abc");
+
+        tester.advance(2);
+        assert_eq!(tester.remaining(), 4);
+        assert_eq!(tester.chunk(), b"c");
+
+        // advance across the wrap boundary.
+        tester.advance(2);
+        assert_eq!(tester.remaining(), 2);
+        assert_eq!(tester.chunk(), b"ef");
+        assert_eq!(tester, *b"ef");
+
+        tester.extend(*b"ghij");
+        assert_eq!(tester.copy_to_bytes(6), &b"efghij"[..]);
+        assert!(tester.is_empty());
+        assert!(!tester.has_remaining());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "bytes")]
+    fn test_buf_advance_out_of_bounds() {
+        use bytes::Buf;
+
+        let mut buf = FixedVecDeque::<[u8; 8]>::new();
+        buf.extend(*b"abc");
+        buf.advance(4);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_zeroize() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut tester = FixedVecDeque::<[u8; 8]>::new();
+        tester.head = 5;
+        tester.extend(*b"secret");
+        tester.pop_front();
+        tester.pop_back();
+
+        tester.zeroize();
+        assert!(tester.is_empty());
+        assert_eq!(tester.head_index(), 0);
+        assert_eq!(tester.data, [0u8; 8]);
+
+        let mut tester = Zeroizing::new(FixedVecDeque::<[u8; 8]>::new());
+        tester.extend(*b"secret");
+        assert_eq!(*tester, *b"secret");
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_strategy(deq in super::fixed_vec_deque_strategy::<[u32; 8], _>(proptest::prelude::any::<u32>())) {
+            proptest::prop_assert!(deq.len() <= deq.capacity());
+            proptest::prop_assert_eq!(deq.len(), deq.iter().count());
+            proptest::prop_assert_eq!(deq.len(), deq.as_slices().0.len() + deq.as_slices().1.len());
+        }
+    }
+
+    #[test]
+    fn test_fmt_write() {
+        use std::fmt::Write;
+
+        let mut bytes = FixedVecDeque::<[u8; 16]>::new();
+        let name = "world";
+        write!(bytes, "hello {}!", name).unwrap();
+        assert_eq!(bytes.to_vec(), b"hello world!");
+
+        write!(bytes, " {:04}", 42).unwrap();
+        assert!(bytes.is_full());
+        assert_eq!(
+            String::from_utf8(bytes.to_vec()).unwrap(),
+            "ello world! 0042"
+        );
+
+        let mut chars = FixedVecDeque::<[char; 8]>::new();
+        write!(chars, "{}: ÅÄÖ", 1).unwrap();
+        assert_eq!(chars.iter().collect::<String>(), "1: ÅÄÖ");
+
+        chars.write_char('x').unwrap();
+        write!(chars, "yz").unwrap();
+        assert_eq!(chars.iter().collect::<String>(), ": ÅÄÖxyz");
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let mut tester = FixedVecDeque::<[u32; 8]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            for len in 0..=tester.capacity() {
+                tester.head = tail_pos;
+                tester.len = 0;
+                tester.extend(0..len as u32);
+
+                let it = tester.iter();
+                assert_eq!(it.len(), len);
+                assert!(it.rev().copied().eq((0..len as u32).rev()));
+
+                let mut it = tester.iter();
+
+                for i in 0..len / 2 {
+                    assert_eq!(it.next(), Some(&(i as u32)));
+                    assert_eq!(it.next_back(), Some(&((len - i - 1) as u32)));
+                }
+
+                assert_eq!(it.len(), len % 2);
+                assert_eq!(it.next(), (len % 2 == 1).then(|| &tester[len / 2]));
+                assert_eq!(it.next(), None);
+                assert_eq!(it.next_back(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_indexed() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+        tester.head = 3;
+        tester.extend([10, 11, 12, 13]);
+
+        let forward = tester.indexed().collect::<Vec<_>>();
+        assert_eq!(forward, [(0, &10), (1, &11), (2, &12), (3, &13)]);
+
+        let mut backward = tester.indexed().rev().collect::<Vec<_>>();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        for (i, v) in tester.indexed().rev() {
+            assert_eq!(tester.get(i), Some(v));
+        }
+    }
+
+    #[test]
+    fn test_swap_take() {
+        let value = Rc::new(());
+
+        {
+            let mut fixed = FixedVecDeque::<[Option<Rc<()>>; 4]>::new();
+
+            for _ in 0..4 {
+                *fixed.push_back() = Some(value.clone());
+            }
+
+            assert_eq!(Rc::strong_count(&value), 5);
+
+            let taken = fixed.swap_take_back(1).unwrap();
+            assert!(taken.is_some());
+            assert_eq!(fixed.len(), 3);
+            assert_eq!(Rc::strong_count(&value), 5);
+            drop(taken);
+            assert_eq!(Rc::strong_count(&value), 4);
+
+            let taken = fixed.swap_take_front(2).unwrap();
+            assert!(taken.is_some());
+            assert_eq!(fixed.len(), 2);
+            drop(taken);
+            assert_eq!(Rc::strong_count(&value), 3);
+
+            assert_eq!(fixed.swap_take_back(2), None);
+            assert_eq!(fixed.swap_take_front(2), None);
+
+            // the vacated slots hold a default value.
+            assert_eq!(fixed.push_back(), &None);
+            assert_eq!(fixed.push_front(), &None);
+            assert_eq!(Rc::strong_count(&value), 3);
+        }
+
+        // no value was dropped twice.
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_pop_by_value() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+        tester.head = 2;
+        tester.extend([1, 2, 3, 4, 5]);
+
+        assert_eq!(tester.pop_front_copied(), Some(2));
+        assert_eq!(tester.pop_back_copied(), Some(5));
+        assert_eq!(tester.pop_front_cloned(), Some(3));
+        assert_eq!(tester.pop_back_cloned(), Some(4));
+        assert_eq!(tester.pop_front_copied(), None);
+        assert_eq!(tester.pop_back_copied(), None);
+        assert_eq!(tester.pop_front_cloned(), None);
+        assert_eq!(tester.pop_back_cloned(), None);
+
+        let mut tester = FixedVecDeque::<[String; 2]>::new();
+        tester.push_back().push_str("abc");
+
+        // NB: the popped element is cloned and left in place.
+        assert_eq!(tester.pop_back_cloned().as_deref(), Some("abc"));
+        assert_eq!(tester.push_back(), "abc");
+    }
+
+    #[test]
+    fn test_position() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+        tester.head = 3;
+        tester.extend([1, 2, 3, 2, 4]);
+        assert_eq!(tester.as_slices(), (&[1, 2][..], &[3, 2, 4][..]));
+
+        assert_eq!(tester.position(&1), Some(0));
+        assert_eq!(tester.position(&2), Some(1));
+        assert_eq!(tester.position(&3), Some(2));
+        assert_eq!(tester.position(&4), Some(4));
+        assert_eq!(tester.position(&5), None);
+        assert!(tester.contains(&4));
+        assert!(!tester.contains(&5));
+    }
+
+    #[test]
+    fn test_pair_mut() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+        assert!(tester.pair_mut(0, 1).is_none());
+
+        tester.head = 2;
+        tester.extend([1, 2, 3, 4]);
+        assert_eq!(tester.as_slices(), (&[1, 2][..], &[3, 4][..]));
+
+        assert!(tester.pair_mut(2, 2).is_none());
+        assert!(tester.pair_mut(0, 4).is_none());
+        assert!(tester.pair_mut(4, 0).is_none());
+
+        let (a, b) = tester.pair_mut(3, 1).unwrap();
+        assert_eq!((*a, *b), (4, 2));
+        mem::swap(a, b);
+        assert_eq!(tester, [1, 4, 3, 2]);
+    }
+
+    #[test]
+    fn test_with_len() {
+        let deq = FixedVecDeque::<[u32; 4]>::with_len(0);
+        assert!(deq.is_empty());
+
+        let mut deq = FixedVecDeque::<[u32; 4]>::with_len(2);
+        assert_eq!(deq.len(), 2);
+        assert!(!deq.is_full());
+        assert_eq!(deq[1], 0);
+        deq[1] = 5;
+        *deq.push_back() = 3;
+        assert_eq!(deq, [0, 5, 3]);
+
+        let mut deq = FixedVecDeque::<[u32; 4]>::with_len(4);
+        assert_eq!(deq.len(), 4);
+        assert!(deq.is_full());
+        deq[0] = 1;
+        deq[3] = 4;
+        assert_eq!(deq, [1, 0, 0, 4]);
+        *deq.push_back() = 5;
+        assert_eq!(deq, [0, 0, 4, 5]);
+
+        let deq = FixedVecDeque::<[u32; 0]>::with_len(0);
+        assert!(deq.is_empty());
+        assert!(deq.is_full());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_len_beyond_capacity() {
+        FixedVecDeque::<[u32; 4]>::with_len(5);
+    }
+
+    #[test]
+    fn test_rposition() {
+        let mut tester = FixedVecDeque::<[u32; 6]>::new();
+        tester.head = 3;
+        tester.extend([1, 2, 1, 3, 2, 4]);
+        assert_eq!(tester.as_slices(), (&[1, 2, 1][..], &[3, 2, 4][..]));
+
+        assert_eq!(tester.rposition(|&v| v == 1), Some(2));
+        assert_eq!(tester.rposition(|&v| v == 2), Some(4));
+        assert_eq!(tester.rposition(|&v| v < 4), Some(4));
+        assert_eq!(tester.rposition(|&v| v == 4), Some(5));
+        assert_eq!(tester.rposition(|&v| v == 5), None);
+
+        let mut seen = Vec::new();
+        tester.rposition(|&v| {
+            seen.push(v);
+            false
+        });
+        assert_eq!(seen, [4, 2, 3, 1, 2, 1]);
+    }
+
+    #[test]
+    fn test_cursors_round_trip() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            for len in 0..=tester.capacity() {
+                tester.head = tail_pos;
+                tester.len = 0;
+                tester.extend(0..len as u32);
+
+                let head = tester.head_index();
+                let tail = tester.tail_index();
+                let len = tester.len();
+                let expected = tester.to_vec();
+                assert_eq!(tail, tail_pos);
+
+                tester.pop_front();
+                tester.pop_back();
+                tester.clear();
+
+                unsafe {
+                    tester.set_cursors(head, len);
+                }
+
+                assert_eq!(tester.head_index(), head);
+                assert_eq!(tester.tail_index(), tail);
+                assert_eq!(tester.len(), len);
+                assert_eq!(tester.to_vec(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_make_contiguous() {
+        let mut tester = FixedVecDeque::<[u32; 6]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            for len in 0..=tester.capacity() {
+                tester.head = tail_pos;
+                tester.len = 0;
+                tester.extend(0..len as u32);
+
+                let expected = (0..len as u32).collect::<Vec<_>>();
+                assert_eq!(tester.make_contiguous(), &expected[..]);
+                assert_eq!(tester.as_slices(), (&expected[..], &[][..]));
+                assert_eq!(tester, expected[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_stable_by() {
+        let mut tester = FixedVecDeque::<[(u32, usize); 8]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            tester.head = tail_pos;
+            tester.len = 0;
+
+            for (tag, key) in [3, 1, 2, 3, 1, 1, 2, 3].iter().enumerate() {
+                *tester.push_back() = (*key, tag);
+            }
+
+            tester.sort_stable_by(|a, b| a.0.cmp(&b.0));
+
+            assert_eq!(
+                tester,
+                [
+                    (1, 1),
+                    (1, 4),
+                    (1, 5),
+                    (2, 2),
+                    (2, 6),
+                    (3, 0),
+                    (3, 3),
+                    (3, 7)
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_const_size() {
+        const N: usize = <[u8; 16] as Array>::SIZE;
+        const M: usize = <&mut [u8; 8] as Array>::SIZE;
+        static BUF: [u8; N + M] = [0; N + M];
+
+        assert_eq!(BUF.len(), 24);
+        assert_eq!(<[u8; 16] as Array>::size(), N);
+        assert_eq!(FixedVecDeque::<[u8; N]>::new().capacity(), N);
+    }
+
+    #[test]
+    fn test_reset() {
+        let value = Rc::new(());
+        let mut tester = FixedVecDeque::<[Option<Rc<()>>; 4]>::new();
+        tester.head = 3;
+
+        for _ in 0..6 {
+            *tester.push_back() = Some(value.clone());
+        }
+
+        tester.pop_front();
+        tester.clear();
+        assert_eq!(Rc::strong_count(&value), 5);
+        assert!(tester.push_back().is_some());
+
+        tester.reset();
+        assert!(tester.is_empty());
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        for _ in 0..4 {
+            assert!(tester.push_back().is_none());
+        }
+    }
+
+    #[test]
+    fn test_back_entry() {
+        let mut tester = FixedVecDeque::<[u32; 3]>::new();
+
+        for v in [1, 1, 2, 2, 3, 4, 4] {
+            let entry = tester.back_entry();
+
+            if entry.back() == Some(&v) {
+                entry.discard();
+            } else {
+                *entry.or_default() = v;
+            }
+        }
+
+        assert_eq!(tester, [2, 3, 4]);
+
+        let len = tester.len();
+        tester.back_entry().discard();
+        assert_eq!(tester.len(), len);
+
+        tester.pop_back();
+        assert_eq!(tester.back_entry().or_default(), &0);
+        assert_eq!(tester, [2, 3, 0]);
+    }
+
+    #[test]
+    fn test_iter_mut_rev() {
+        let mut tester = FixedVecDeque::<[u32; 8]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            for len in 0..=tester.capacity() {
+                tester.head = tail_pos;
+                tester.len = 0;
+                tester.extend(0..len as u32);
+
+                assert!(tester
+                    .iter_mut()
+                    .rev()
+                    .map(|v| *v)
+                    .eq((0..len as u32).rev()));
+
+                let mut refs = Vec::new();
+                let mut it = tester.iter_mut();
+
+                loop {
+                    match (it.next(), it.next_back()) {
+                        (Some(a), Some(b)) => {
+                            refs.push(a);
+                            refs.push(b);
+                        }
+                        (Some(a), None) => refs.push(a),
+                        (None, _) => break,
+                    }
+                }
+
+                assert_eq!(refs.len(), len);
+
+                for v in refs {
+                    *v += 100;
+                }
+
+                assert!(tester.iter().copied().eq(100..100 + len as u32));
+            }
+        }
+    }
+
+    #[test]
+    fn test_as_ptr() {
+        let mut tester = FixedVecDeque::<[u32; 5]>::new();
+
+        for tail_pos in 0..tester.capacity() {
+            for len in 0..=tester.capacity() {
+                tester.head = tail_pos;
+                tester.len = 0;
+                tester.extend(0..len as u32);
+
+                let ptr = tester.as_ptr();
+                let mut raw = Vec::new();
+
+                for i in 0..tester.len() {
+                    let off = (tester.tail_index() + i) % tester.capacity();
+                    raw.push(unsafe { *ptr.add(off) });
+                }
+
+                let (a, b) = tester.as_slices();
+                assert_eq!(raw, [a, b].concat());
+
+                let tail = tester.tail_index();
+                let ptr = tester.as_mut_ptr();
+
+                for i in 0..len {
+                    unsafe { *ptr.add((tail + i) % 5) *= 2 };
+                }
+
+                assert!(tester.iter().copied().eq((0..len as u32).map(|v| v * 2)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_sized() {
+        let mut tester = FixedVecDeque::<[u32; 0]>::new();
+        assert!(tester.is_empty());
+        assert!(tester.is_full());
+        assert_eq!(tester.capacity(), 0);
+        assert_eq!(tester.remaining_capacity(), 0);
+
+        assert_eq!(tester.pop_front(), None);
+        assert_eq!(tester.pop_back(), None);
+        assert_eq!(tester.front(), None);
+        assert_eq!(tester.back(), None);
+        assert_eq!(tester.get(0), None);
+        assert_eq!(tester.remove(0), None);
+        assert_eq!(tester.iter().count(), 0);
+        assert_eq!(tester.iter_mut().count(), 0);
+        assert_eq!(tester.iter().next_back(), None);
+        assert_eq!(tester.range(..).count(), 0);
+        assert_eq!(tester.range_mut(..).count(), 0);
+        assert_eq!(tester.windows(1).count(), 0);
+        assert_eq!(tester.chunks(1).count(), 0);
+        assert!(tester.slice(..).is_empty());
+        assert_eq!(tester.as_slices(), (&[][..], &[][..]));
+        assert_eq!(tester.as_mut_slices(), (&mut [][..], &mut [][..]));
+        assert!(tester.make_contiguous().is_empty());
+        assert_eq!(tester.drain().count(), 0);
+        assert_eq!(tester.to_vec(), []);
+
+        tester.truncate(0);
+        tester.retain(|_| true);
+        tester.extend_front(None);
+        tester.extend(None::<u32>);
+        tester.clear();
+        assert_eq!(tester, []);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add to a zero-sized deque")]
+    fn test_zero_sized_push_back() {
+        let mut tester = FixedVecDeque::<[u32; 0]>::new();
+        tester.push_back();
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add to a zero-sized deque")]
+    fn test_zero_sized_push_front() {
+        let mut tester = FixedVecDeque::<[u32; 0]>::new();
+        tester.push_front();
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        for head in 0..5 {
+            let mut expected = Vec::new();
+            let mut buf = FixedVecDeque::<[u32; 5]>::new();
+            buf.head = head;
+
+            for v in [7, 3, 9, 3, 1, 8, 5, 0, 6, 9, 2, 7] {
+                if expected.len() == 5 {
+                    if expected[0] >= v {
+                        assert_eq!(buf.insert_sorted(v), None);
+                        assert_eq!(buf, expected[..], "head: {}", head);
+                        continue;
+                    }
+
+                    expected.remove(0);
+                }
+
+                assert_eq!(buf.insert_sorted(v).copied(), Some(v));
+
+                let index = expected.partition_point(|e| *e <= v);
+                expected.insert(index, v);
+
+                assert_eq!(buf, expected[..], "head: {}", head);
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_sorted_below_min() {
+        let mut buf = FixedVecDeque::<[u32; 3]>::new();
+        buf.head = 2;
+
+        for v in [5, 7, 9] {
+            buf.insert_sorted(v);
+        }
+
+        assert_eq!(buf.insert_sorted(1), None);
+        assert_eq!(buf, [5, 7, 9]);
+        assert_eq!(buf.insert_sorted(5), None);
+        assert_eq!(buf, [5, 7, 9]);
+        assert_eq!(buf.insert_sorted(6), Some(&mut 6));
+        assert_eq!(buf, [6, 7, 9]);
+    }
+
+    #[test]
+    fn test_insert_sorted_drops() {
+        let mut buf = FixedVecDeque::<[Rc<u32>; 3]>::new();
+        let values = (0..6).map(Rc::new).collect::<Vec<_>>();
+
+        for v in &values {
+            buf.insert_sorted(v.clone());
+        }
+
+        assert_eq!(buf.iter().map(|v| **v).collect::<Vec<_>>(), vec![3, 4, 5]);
+
+        for v in &values[..3] {
+            assert_eq!(Rc::strong_count(v), 1);
+        }
+
+        for v in &values[3..] {
+            assert_eq!(Rc::strong_count(v), 2);
+        }
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        fn merged(a: &[u32], b: &[u32]) -> Vec<u32> {
+            let mut out = a.iter().chain(b).copied().collect::<Vec<_>>();
+            out.sort();
+            out.truncate(5);
+            out
+        }
+
+        let cases: &[(&[u32], &[u32])] = &[
+            (&[], &[]),
+            (&[1, 2, 3], &[]),
+            (&[], &[1, 2, 3]),
+            (&[1, 3, 5], &[2, 4]),
+            (&[2, 3, 4, 5], &[3, 4, 5, 6]),
+            (&[1, 2, 3, 4, 5], &[0, 6]),
+            (&[5, 6, 7], &[1, 2, 3, 4]),
+            (&[1, 2, 3, 4, 5], &[5, 6, 7]),
+            (&[3, 3, 3], &[3, 3, 3]),
+        ];
+
+        for &(a, b) in cases {
+            for head in 0..5 {
+                let mut this = FixedVecDeque::<[u32; 5]>::new();
+                this.head = head;
+                this.extend(a.iter().copied());
+
+                let mut other = FixedVecDeque::<[u32; 5]>::new();
+                other.head = 4 - head;
+                other.extend(b.iter().copied());
+
+                this.merge_sorted(&other);
+                assert_eq!(this, merged(a, b)[..], "a: {:?}, b: {:?}", a, b);
+                assert_eq!(other, b);
+            }
+        }
+
+        let mut empty = FixedVecDeque::<[u32; 0]>::new();
+        empty.merge_sorted(&FixedVecDeque::new());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_wrap_point() {
+        for head in 0..4 {
+            for len in 0..=4 {
+                let mut buf = FixedVecDeque::<[u32; 4]>::new();
+                buf.head = head;
+                buf.extend(0..len);
+
+                let (a, b) = buf.as_slices();
+                let expected = if b.is_empty() { None } else { Some(a.len()) };
+                assert_eq!(buf.wrap_point(), expected, "head: {}, len: {}", head, len);
+                assert_eq!(buf.is_contiguous(), expected.is_none());
+            }
+        }
+
+        assert!(FixedVecDeque::<[u32; 0]>::new().is_contiguous());
+    }
+
+    #[test]
+    fn test_try_from_iter() {
+        let buf = FixedVecDeque::<[u32; 4]>::try_from_iter(0..4).unwrap();
+        assert!(buf.is_full());
+        assert_eq!(buf, [0, 1, 2, 3]);
+
+        let buf = FixedVecDeque::<[u32; 4]>::try_from_iter(0..2).unwrap();
+        assert_eq!(buf, [0, 1]);
+
+        let err = FixedVecDeque::<[u32; 4]>::try_from_iter(0..5).unwrap_err();
+        assert_eq!(err.capacity(), 4);
+
+        // stops consuming the iterator at the first element which doesn't fit.
+        let mut it = 0..10;
+        assert!(FixedVecDeque::<[u32; 4]>::try_from_iter(&mut it).is_err());
+        assert_eq!(it.next(), Some(5));
+
+        assert!(FixedVecDeque::<[u32; 0]>::try_from_iter(0..0).is_ok());
+        assert!(FixedVecDeque::<[u32; 0]>::try_from_iter(0..1).is_err());
+    }
+
+    #[test]
+    fn test_extend_bounded() {
+        let mut bounded = FixedVecDeque::<[u32; 4]>::new();
+        bounded.head = 2;
+        bounded.extend_bounded(0..10);
+        assert_eq!(bounded, [0, 1, 2, 3]);
+
+        let mut unbounded = FixedVecDeque::<[u32; 4]>::new();
+        unbounded.head = 2;
+        unbounded.extend(0..10);
+        assert_eq!(unbounded, [6, 7, 8, 9]);
+
+        // only pulls as many elements from the iterator as fits.
+        let mut it = 0..10;
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.extend([10, 11]);
+        buf.extend_bounded(&mut it);
+        assert_eq!(buf, [10, 11, 0, 1]);
+        assert_eq!(it.next(), Some(2));
+
+        buf.extend_bounded(&mut it);
+        assert_eq!(buf, [10, 11, 0, 1]);
+        assert_eq!(it.next(), Some(3));
+
+        let mut empty = FixedVecDeque::<[u32; 0]>::new();
+        empty.extend_bounded(0..10);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_eq_vec_deque() {
+        use std::collections::VecDeque;
+
+        let mut a = FixedVecDeque::<[u32; 4]>::new();
+        a.head = 1;
+        a.extend([1, 2, 3, 4]);
+
+        let mut b = VecDeque::with_capacity(4);
+        b.extend([3, 4]);
+        b.push_front(2);
+        b.push_front(1);
+        assert_eq!(a, b);
+
+        b.pop_back();
+        assert_ne!(a, b);
+        a.pop_back();
+        assert_eq!(a, b);
+
+        b.push_back(5);
+        assert_ne!(a, b);
+
+        assert_eq!(FixedVecDeque::<[u32; 4]>::new(), VecDeque::<u32>::new());
+        assert_ne!(FixedVecDeque::<[u32; 4]>::new(), VecDeque::from(vec![1]));
+    }
+
+    #[test]
+    fn test_into_vec_deque() {
+        use std::collections::VecDeque;
+
+        for head in 0..4 {
+            let mut buf = FixedVecDeque::<[Rc<u32>; 4]>::new();
+            buf.head = head;
+            buf.extend((0..6).map(Rc::new));
+            buf.pop_front();
+
+            let deq = VecDeque::from(buf);
+            assert_eq!(deq.len(), 3);
+            assert_eq!(deq.iter().map(|v| **v).collect::<Vec<_>>(), vec![3, 4, 5]);
+
+            for v in &deq {
+                assert_eq!(Rc::strong_count(v), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_iter_physical() {
+        for head in 0..4 {
+            for len in 0..6 {
+                let mut buf = FixedVecDeque::<[u32; 4]>::new();
+                buf.head = head;
+                buf.extend(0..len);
+
+                let mut count = 0;
+
+                for (i, (offset, v)) in buf.iter_physical().enumerate() {
+                    assert_eq!(offset, buf.ptr_index(i));
+                    assert!(std::ptr::eq(v, &buf[i]));
+                    count += 1;
+                }
+
+                assert_eq!(count, buf.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_to_front() {
+        for head in 0..4 {
+            for len in 1..=4 {
+                for index in 0..len {
+                    let mut buf = FixedVecDeque::<[u32; 4]>::new();
+                    buf.head = head;
+                    buf.extend(0..len as u32);
+
+                    let mut expected = (0..len as u32).collect::<Vec<_>>();
+                    expected.rotate_left(index);
+
+                    buf.rotate_to_front(index);
+                    assert_eq!(buf.front(), Some(&(index as u32)));
+                    assert_eq!(buf, expected[..]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut tester = FixedVecDeque::<[u32; 6]>::new();
+
+        for head in 0..6 {
+            for len in 0..=6 {
+                for n in 0..=len {
+                    let mut expected = (0..len as u32).collect::<Vec<_>>();
+                    expected.rotate_left(n);
+
+                    tester.head = head;
+                    tester.len = 0;
+                    tester.extend(0..len as u32);
+                    tester.rotate_left(n);
+                    assert_eq!(
+                        tester,
+                        expected[..],
+                        "head: {}, len: {}, n: {}",
+                        head,
+                        len,
+                        n
+                    );
+
+                    let mut expected = (0..len as u32).collect::<Vec<_>>();
+                    expected.rotate_right(n);
+
+                    tester.head = head;
+                    tester.len = 0;
+                    tester.extend(0..len as u32);
+                    tester.rotate_right(n);
+                    assert_eq!(
+                        tester,
+                        expected[..],
+                        "head: {}, len: {}, n: {}",
+                        head,
+                        len,
+                        n
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_full_wrapped() {
+        let mut buf = FixedVecDeque::<[u32; 1024]>::new();
+        buf.extend(0..1500);
+        assert!(buf.is_full());
+        assert!(!buf.is_contiguous());
+
+        let mut expected = (476..1500).collect::<Vec<_>>();
+
+        buf.rotate_left(1000);
+        expected.rotate_left(1000);
+        assert_eq!(buf, expected[..]);
+
+        buf.rotate_right(7);
+        expected.rotate_right(7);
+        assert_eq!(buf, expected[..]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_left_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.extend([1, 2]);
+        buf.rotate_left(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_to_front_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.extend([1, 2]);
+        buf.rotate_to_front(2);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let deq = FixedVecDeque::<[u32; 4]>::from_fn(4, |i| (i * i) as u32);
+        assert!(deq.is_full());
+        assert_eq!(deq, [0, 1, 4, 9]);
+
+        let mut calls = Vec::new();
+        let deq = FixedVecDeque::<[String; 4]>::from_fn(2, |i| {
+            calls.push(i);
+            i.to_string()
+        });
+        assert_eq!(deq, ["0", "1"]);
+        assert_eq!(calls, vec![0, 1, 2, 3]);
+
+        let deq = FixedVecDeque::<[u32; 0]>::from_fn(0, |_| unreachable!());
+        assert!(deq.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_fn_beyond_capacity() {
+        FixedVecDeque::<[u32; 4]>::from_fn(5, |i| i as u32);
+    }
+
+    #[test]
+    fn test_next_overwrite() {
+        let mut buf = FixedVecDeque::<[u32; 3]>::new();
+        buf.head = 2;
+
+        for v in 0..3 {
+            assert_eq!(buf.next_overwrite(), None);
+            *buf.push_back() = v;
+        }
+
+        for v in 3..6 {
+            assert_eq!(buf.next_overwrite(), Some(&(v - 3)));
+            let front = *buf.front().unwrap();
+            *buf.push_back() = v;
+            assert!(!buf.contains(&front));
+        }
+
+        buf.pop_back();
+        assert_eq!(buf.next_overwrite(), None);
+
+        assert_eq!(FixedVecDeque::<[u32; 0]>::new().next_overwrite(), None);
+    }
+
+    #[test]
+    fn test_resize_with() {
+        #[derive(Debug, Default, PartialEq, Eq)]
+        struct Token(u32);
+
+        let mut next = 0;
+        let mut make = || {
+            next += 1;
+            Token(next)
+        };
+
+        let mut buf = FixedVecDeque::<[Token; 4]>::new();
+        buf.head = 3;
+        buf.resize_with(2, &mut make);
+        assert_eq!(buf, [Token(1), Token(2)]);
+
+        buf.resize_with(4, &mut make);
+        assert!(buf.is_full());
+        assert_eq!(buf, [Token(1), Token(2), Token(3), Token(4)]);
+
+        buf.resize_with(1, &mut make);
+        assert_eq!(buf, [Token(1)]);
+        assert_eq!(next, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_resize_with_beyond_capacity() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.resize_with(5, || 0);
+    }
+
+    #[test]
+    fn test_slices_full_head_zero() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.extend([1, 2, 3, 4]);
+        assert!(buf.is_full());
+        assert_eq!(buf.head, 0);
+
+        assert_eq!(buf.as_slices(), (&[1, 2, 3, 4][..], &[][..]));
+        assert_eq!(buf.as_mut_slices(), (&mut [1, 2, 3, 4][..], &mut [][..]));
+
+        // wrap around the buffer once more to end up with the head at zero again.
+        buf.extend([5, 6, 7, 8]);
+        assert_eq!(buf.head, 0);
+
+        assert_eq!(buf.as_slices(), (&[5, 6, 7, 8][..], &[][..]));
+        assert_eq!(buf.as_mut_slices(), (&mut [5, 6, 7, 8][..], &mut [][..]));
+
+        for head in 0..4 {
+            let mut buf = FixedVecDeque::<[u32; 4]>::new();
+            buf.head = head;
+            buf.extend([1, 2, 3, 4]);
+
+            let (a, b) = buf.as_slices();
+            let expected = a.iter().chain(b).copied().collect::<Vec<_>>();
+            assert_eq!(expected, vec![1, 2, 3, 4]);
+
+            let (a, b) = buf.as_mut_slices();
+            let actual = a.iter().chain(b.iter()).copied().collect::<Vec<_>>();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_swap_logical() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.head = 3;
+        buf.extend([1, 2, 3]);
+
+        assert!(buf.swap_logical(0, 2));
+        assert_eq!(buf, [3, 2, 1]);
+
+        assert!(buf.swap_logical(1, 1));
+        assert_eq!(buf, [3, 2, 1]);
+
+        // the slot at index 3 is within capacity, but not live.
+        assert!(!buf.swap_logical(0, 3));
+        assert!(!buf.swap_logical(3, 0));
+        assert!(!buf.swap_logical(3, 3));
+        assert!(!buf.swap_logical(usize::MAX, 0));
+        assert_eq!(buf, [3, 2, 1]);
+
+        let mut empty = FixedVecDeque::<[u32; 0]>::new();
+        assert!(!empty.swap_logical(0, 0));
     }
-}
 
-/// An iterator over the elements of a `FixedVecDeque`.
-///
-/// This `struct` is created by the [`iter`] method on [`FixedVecDeque`]. See its
-/// documentation for more.
-///
-/// [`iter`]: struct.FixedVecDeque.html#method.iter
-/// [`FixedVecDeque`]: struct.FixedVecDeque.html
-pub struct IterMut<'a, T: 'a>
-where
-    T: Array,
-{
-    data: *mut T::Item,
-    head: usize,
-    len: usize,
-    marker: marker::PhantomData<&'a ()>,
-}
+    #[test]
+    fn test_contiguous_mut() {
+        for head in 0..5 {
+            for len in 0..=5 {
+                let mut buf = FixedVecDeque::<[u32; 5]>::new();
+                buf.head = head;
+                buf.extend(0..len);
+
+                let slice = buf.contiguous_mut();
+                assert_eq!(slice.len(), len as usize);
+
+                for v in slice.iter_mut() {
+                    *v *= 10;
+                }
 
-impl<'a, T: 'a> Iterator for IterMut<'a, T>
-where
-    T: Array,
-{
-    type Item = &'a mut T::Item;
+                let expected = (0..len).map(|v| v * 10).collect::<Vec<_>>();
+                assert!(buf.is_contiguous());
+                assert_eq!(buf, expected[..]);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.len == 0 {
-            return None;
+                // the deque still behaves as a ring buffer after being rearranged.
+                *buf.push_back() = 100;
+                assert_eq!(buf.back(), Some(&100));
+                *buf.push_front() = 200;
+                assert_eq!(buf.front(), Some(&200));
+            }
         }
-
-        let tail = T::wrap_sub(self.head, self.len);
-        self.len -= 1;
-        Some(unsafe { &mut *self.data.add(tail) })
     }
-}
 
-impl<'a, T: 'a> IntoIterator for &'a FixedVecDeque<T>
-where
-    T: Array,
-{
-    type Item = &'a T::Item;
-    type IntoIter = Iter<'a, T>;
+    #[test]
+    fn test_extend_ref() {
+        let mut deq = FixedVecDeque::<[u32; 4]>::new();
+        deq.extend([1, 2, 3].iter());
+        assert_eq!(deq, [1, 2, 3]);
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+        let other = vec![4, 5, 6];
+        deq.extend(&other);
+        assert_eq!(deq, [3, 4, 5, 6]);
     }
-}
 
-impl<A> Extend<A::Item> for FixedVecDeque<A>
-where
-    A: Array,
-{
-    fn extend<T: IntoIterator<Item = A::Item>>(&mut self, iter: T) {
-        for elt in iter {
-            *self.push_back() = elt;
+    #[test]
+    fn test_min_max() {
+        let empty = FixedVecDeque::<[(u32, char); 4]>::new();
+        assert_eq!(empty.min_element(), None);
+        assert_eq!(empty.max_element(), None);
+        assert_eq!(empty.min_by(|a, b| a.0.cmp(&b.0)), None);
+        assert_eq!(empty.max_by(|a, b| a.0.cmp(&b.0)), None);
+        assert_eq!(empty.min_by_key(|v| v.0), None);
+        assert_eq!(empty.max_by_key(|v| v.0), None);
+
+        let values = [(2, 'a'), (1, 'b'), (3, 'c'), (1, 'd'), (3, 'e')];
+
+        for head in 0..5 {
+            let mut buf = FixedVecDeque::<[(u32, char); 5]>::new();
+            buf.head = head;
+            buf.extend(values);
+
+            assert_eq!(buf.min_element(), Some(&(1, 'b')));
+            assert_eq!(buf.max_element(), Some(&(3, 'e')));
+
+            // ties resolve to the first minimum and the last maximum.
+            assert_eq!(buf.min_by(|a, b| a.0.cmp(&b.0)), Some(&(1, 'b')));
+            assert_eq!(buf.max_by(|a, b| a.0.cmp(&b.0)), Some(&(3, 'e')));
+            assert_eq!(buf.min_by_key(|v| v.0), Some(&(1, 'b')));
+            assert_eq!(buf.max_by_key(|v| v.0), Some(&(3, 'e')));
+            assert_eq!(buf.min_by_key(|v| cmp::Reverse(v.1)), Some(&(3, 'e')));
         }
     }
-}
 
-impl<T> fmt::Debug for FixedVecDeque<T>
-where
-    T: Array,
-    T::Item: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_list().entries(self).finish()
+    #[test]
+    fn test_boxed_array() {
+        use super::BoxedArray;
+
+        let mut buf = FixedVecDeque::<BoxedArray<String, 4>>::new();
+        assert_eq!(buf.capacity(), 4);
+        assert!(buf.is_empty());
+
+        for v in ["a", "b", "c", "d", "e"] {
+            *buf.push_back() = v.to_string();
+        }
+
+        assert_eq!(buf, ["b", "c", "d", "e"]);
+        *buf.push_front() = String::from("f");
+        assert_eq!(buf, ["f", "b", "c", "d"]);
+
+        let mut other = buf.clone();
+        assert_eq!(other, buf);
+
+        other.pop_back();
+        other.rotate_left(1);
+        assert_eq!(other, ["b", "c", "f"]);
+
+        // moving the deque around keeps the elements in place on the heap.
+        let ptr = buf.as_ptr();
+        let moved = Box::new(buf);
+        assert_eq!(moved.as_ptr(), ptr);
+        assert_eq!(*moved, ["f", "b", "c", "d"]);
+
+        let squares = FixedVecDeque::<BoxedArray<usize, 4>>::from_fn(3, |i| i * i);
+        assert_eq!(squares, [0, 1, 4]);
     }
-}
 
-impl<A> FromIterator<A::Item> for FixedVecDeque<A>
-where
-    A: Array,
-    A::Item: Default,
-{
-    fn from_iter<T: IntoIterator<Item = A::Item>>(iter: T) -> FixedVecDeque<A> {
-        let mut deq = FixedVecDeque::new();
-        deq.extend(iter);
-        deq
+    #[test]
+    fn test_partial_ord_slices() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.head = 3;
+        buf.extend([1, 2, 3]);
+
+        assert!(buf > [1, 2][..]);
+        assert!(buf < [1, 2, 3, 0][..]);
+        assert!(buf < [1, 3][..]);
+        assert!(buf > [0, 9, 9, 9][..]);
+        let (empty, same): (&[u32], &[u32]) = (&[], &[1, 2, 3]);
+        assert!(buf > empty);
+        assert!(buf <= same);
+        assert!(buf >= [1, 2, 3]);
+        assert!(buf < [2]);
+        assert!(buf > vec![1, 2]);
+        assert!(buf < vec![1, 2, 3, 4]);
+        assert_eq!(buf, vec![1, 2, 3]);
+
+        assert_eq!(buf.partial_cmp(&[1, 2, 3]), Some(cmp::Ordering::Equal));
+        assert_eq!(
+            FixedVecDeque::<[f32; 2]>::try_from(&[f32::NAN][..])
+                .unwrap()
+                .partial_cmp(&[1.0]),
+            None
+        );
     }
-}
 
-/// Types that can be used as the backing store for a FixedVecDeque.
-///
-/// # Safety
-///
-/// Implementor must ensure that the type is array appropriate.
-pub unsafe trait Array {
-    /// The type of the array's elements.
-    type Item;
+    #[test]
+    fn test_split_at_mut() {
+        for head in 0..5 {
+            for len in 0..=5 {
+                for mid in 0..=len {
+                    let mut buf = FixedVecDeque::<[u32; 5]>::new();
+                    buf.head = head;
+                    buf.extend(0..len as u32);
+
+                    let (mut left, mut right) = buf.split_at_mut(mid);
+                    assert_eq!(left.len(), mid);
+                    assert_eq!(right.len(), len - mid);
+
+                    // hold on to mutable references from both halves at the same time.
+                    let lefts = left.iter_mut().collect::<Vec<_>>();
+                    let rights = right.iter_mut().collect::<Vec<_>>();
+
+                    for v in lefts {
+                        *v += 100;
+                    }
 
-    /// Returns the number of items the array can hold.
-    fn size() -> usize;
+                    for v in rights {
+                        *v += 200;
+                    }
 
-    /// Returns a pointer to the first element of the array.
-    fn ptr(&self) -> *const Self::Item;
+                    assert_eq!(left.iter().count(), mid);
+                    assert_eq!(right.into_iter().count(), len - mid);
 
-    /// Returns a mutable pointer to the first element of the array.
-    fn ptr_mut(&mut self) -> *mut Self::Item;
+                    let expected = (0..len as u32)
+                        .map(|v| if (v as usize) < mid { v + 100 } else { v + 200 })
+                        .collect::<Vec<_>>();
 
-    /// Returns the index in the underlying buffer for a given logical element
-    /// index + addend.
-    #[inline]
-    fn wrap_add(idx: usize, addend: usize) -> usize {
-        (idx + addend) % Self::size()
+                    assert_eq!(buf, expected[..]);
+                }
+            }
+        }
     }
 
-    /// Returns the index in the underlying buffer for a given logical element
-    /// index - subtrahend.
-    #[inline]
-    fn wrap_sub(idx: usize, subtrahend: usize) -> usize {
-        if subtrahend > idx {
-            Self::size() - (subtrahend - idx)
-        } else {
-            idx - subtrahend
-        }
+    #[test]
+    #[should_panic]
+    fn test_split_at_mut_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.extend([1, 2]);
+        buf.split_at_mut(3);
     }
-}
 
-unsafe impl<const N: usize, T> Array for [T; N] {
-    type Item = T;
+    #[test]
+    fn test_fill_range() {
+        let mut tester = FixedVecDeque::<[u32; 6]>::new();
 
-    #[inline]
-    fn size() -> usize {
-        N
+        for head in 0..6 {
+            for start in 0..=5 {
+                for end in start..=5 {
+                    tester.head = head;
+                    tester.len = 0;
+                    tester.extend([1, 2, 3, 4, 5]);
+
+                    let mut expected = [1, 2, 3, 4, 5];
+                    expected[start..end].fill(0);
+
+                    tester.fill_range(start..end, 0);
+                    assert_eq!(
+                        tester,
+                        expected[..],
+                        "head: {}, range: {}..{}",
+                        head,
+                        start,
+                        end
+                    );
+                }
+            }
+        }
+
+        tester.fill_range(.., 9);
+        assert_eq!(tester, [9, 9, 9, 9, 9]);
+        tester.fill_range(1..=3, 0);
+        assert_eq!(tester, [9, 0, 0, 0, 9]);
     }
 
-    #[inline]
-    fn ptr(&self) -> *const Self::Item {
-        self.as_ptr()
+    #[test]
+    #[should_panic]
+    fn test_fill_range_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 6]>::new();
+        buf.extend([1, 2, 3]);
+        buf.fill_range(1..4, 0);
     }
 
-    #[inline]
-    fn ptr_mut(&mut self) -> *mut Self::Item {
-        self.as_mut_ptr()
+    #[test]
+    fn test_push_back_with_evict() {
+        let mut buf = FixedVecDeque::<[u32; 2]>::new();
+        let mut evictions = 0;
+
+        for v in 0..5 {
+            *buf.push_back_with_evict(|e| {
+                assert_eq!(*e, v - 2);
+                evictions += 1;
+            }) = v;
+        }
+
+        assert_eq!(evictions, 3);
+        assert_eq!(buf, [3, 4]);
+
+        // evicted values can be moved out of the slot before it's reused.
+        let mut buf = FixedVecDeque::<[String; 2]>::new();
+        let mut evicted = Vec::new();
+
+        for v in ["a", "b", "c"] {
+            buf.push_back_with_evict(|e| evicted.push(mem::take(e)))
+                .push_str(v);
+        }
+
+        assert_eq!(buf, ["b", "c"]);
+        assert_eq!(evicted, ["a"]);
     }
-}
 
-unsafe impl<const N: usize, T> Array for &mut [T; N] {
-    type Item = T;
+    #[test]
+    fn test_iter_fold() {
+        for head in 0..5 {
+            for len in 0..=7 {
+                let mut buf = FixedVecDeque::<[u32; 5]>::new();
+                buf.head = head;
+                buf.extend(0..len);
+
+                let expected = buf.iter().copied().collect::<Vec<_>>();
+
+                let folded = buf.iter().fold(Vec::new(), |mut out, v| {
+                    out.push(*v);
+                    out
+                });
+
+                assert_eq!(folded, expected);
+                assert_eq!(buf.iter().sum::<u32>(), expected.iter().sum());
+
+                // fold the remainder of a partially consumed iterator.
+                let mut it = buf.iter();
+                it.next();
+                it.next_back();
+
+                let folded = it.fold(Vec::new(), |mut out, v| {
+                    out.push(*v);
+                    out
+                });
+
+                let rest = if expected.len() < 2 {
+                    &[][..]
+                } else {
+                    &expected[1..expected.len() - 1]
+                };
 
-    #[inline]
-    fn size() -> usize {
-        N
+                assert_eq!(folded, rest);
+            }
+        }
+
+        let empty = FixedVecDeque::<[u32; 0]>::new();
+        assert_eq!(empty.iter().fold(Vec::new(), |_, v| vec![*v]), []);
     }
 
-    #[inline]
-    fn ptr(&self) -> *const Self::Item {
-        self.as_ptr()
+    #[test]
+    fn test_compact() {
+        // aligned.
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf.compact(), 0);
+        assert_eq!(buf.tail_index(), 0);
+        assert_eq!(buf, [1, 2, 3]);
+
+        // contiguous, but offset by one.
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.head = 1;
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf.tail_index(), 1);
+        assert_eq!(buf.compact(), 3);
+        assert_eq!(buf.tail_index(), 0);
+        assert_eq!(buf, [1, 2, 3]);
+        assert_eq!(buf.compact(), 0);
+
+        // full and wrapped.
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.extend(0..8);
+        assert!(!buf.is_contiguous());
+        assert_eq!(buf.compact(), 5);
+        assert_eq!(buf.tail_index(), 0);
+        assert_eq!(buf.as_slices(), (&[3, 4, 5, 6, 7][..], &[][..]));
+
+        *buf.push_back() = 8;
+        assert_eq!(buf, [4, 5, 6, 7, 8]);
+
+        // empty.
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.head = 3;
+        assert_eq!(buf.compact(), 0);
+        assert_eq!(buf.tail_index(), 0);
     }
 
-    #[inline]
-    fn ptr_mut(&mut self) -> *mut Self::Item {
-        self.as_mut_ptr()
+    #[test]
+    fn test_eq_unordered() {
+        let cases: &[(&[u32], &[u32], bool)] = &[
+            (&[], &[], true),
+            (&[1, 2, 3], &[3, 1, 2], true),
+            (&[1, 1, 2, 5, 1], &[5, 1, 2, 1, 1], true),
+            // same elements, different multiplicities.
+            (&[1, 1, 2], &[1, 2, 2], false),
+            // different lengths.
+            (&[1, 2], &[1, 2, 2], false),
+            (&[1, 2, 3], &[1, 2, 4], false),
+        ];
+
+        let mut a = FixedVecDeque::<[u32; 5]>::new();
+        let mut b = FixedVecDeque::<[u32; 5]>::new();
+
+        for &(left, right, expected) in cases {
+            for head in 0..5 {
+                a.head = head;
+                a.len = 0;
+                a.extend(left.iter().copied());
+
+                b.head = 4 - head;
+                b.len = 0;
+                b.extend(right.iter().copied());
+
+                assert_eq!(a.eq_unordered(&b), expected, "{:?} {:?}", left, right);
+                assert_eq!(b.eq_unordered(&a), expected, "{:?} {:?}", right, left);
+            }
+        }
     }
-}
 
-impl<A> Eq for FixedVecDeque<A>
-where
-    A: Array,
-    A::Item: Eq,
-{
-}
+    #[test]
+    fn test_pop_front_n() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+
+        for head in 0..4 {
+            for n in 0..6 {
+                tester.head = head;
+                tester.len = 0;
+                tester.extend([1, 2, 3]);
+
+                let popped = cmp::min(n, 3);
+                assert_eq!(tester.pop_front_n(n), popped);
+                assert_eq!(tester, [1, 2, 3][popped..]);
+                assert_eq!(tester.head_index(), (head + 3) % 4);
+
+                if tester.is_empty() {
+                    assert_eq!(tester.pop_front_n(1), 0);
+                }
 
-impl<A, B> PartialEq<FixedVecDeque<B>> for FixedVecDeque<A>
-where
-    A: Array,
-    B: Array,
-    A::Item: PartialEq<B::Item>,
-{
-    fn eq(&self, other: &FixedVecDeque<B>) -> bool {
-        if self.len() != other.len() {
-            return false;
+                // pushing to the back continues where it left off.
+                *tester.push_back() = 4;
+                assert_eq!(tester.len(), 4 - popped);
+                assert_eq!(tester.back(), Some(&4));
+            }
         }
+    }
 
-        let (sa, sb) = self.as_slices();
-        let (oa, ob) = other.as_slices();
-
-        match sa.len().cmp(&oa.len()) {
-            cmp::Ordering::Less => {
-                // Always divisible in three sections, for example:
-                // self:  [a b c|d e f]
-                // other: [0 1 2 3|4 5]
-                // front = 3, mid = 1,
-                // [a b c] == [0 1 2] && [d] == [3] && [e f] == [4 5]
-                let front = sa.len();
-                let mid = oa.len() - front;
+    #[test]
+    fn test_pop_back_n() {
+        let mut tester = FixedVecDeque::<[u32; 4]>::new();
+
+        for head in 0..4 {
+            for n in 0..6 {
+                tester.head = head;
+                tester.len = 0;
+                tester.extend([1, 2, 3]);
+
+                let popped = cmp::min(n, 3);
+                assert_eq!(tester.pop_back_n(n), popped);
+                assert_eq!(tester, [1, 2, 3][..3 - popped]);
+                assert_eq!(tester.tail_index(), head);
+
+                if tester.is_empty() {
+                    assert_eq!(tester.pop_back_n(1), 0);
+                }
 
-                let (oa_front, oa_mid) = oa.split_at(front);
-                let (sb_mid, sb_back) = sb.split_at(mid);
-                debug_assert_eq!(sa.len(), oa_front.len());
-                debug_assert_eq!(sb_mid.len(), oa_mid.len());
-                debug_assert_eq!(sb_back.len(), ob.len());
-                sa == oa_front && sb_mid == oa_mid && sb_back == ob
+                // pushing to the back reuses the removed slots.
+                *tester.push_back() = 4;
+                assert_eq!(tester.len(), 4 - popped);
+                assert_eq!(tester.tail_index(), head);
+                assert_eq!(tester.back(), Some(&4));
             }
-            cmp::Ordering::Equal => sa == oa && sb == ob,
-            cmp::Ordering::Greater => {
-                let front = oa.len();
-                let mid = sa.len() - front;
+        }
+    }
+
+    #[test]
+    fn test_push_back_n() {
+        for head in 0..4 {
+            for len in 0..=4 {
+                for n in 0..=9 {
+                    let mut buf = FixedVecDeque::<[String; 4]>::new();
+                    buf.head = head;
+                    buf.extend((0..len).map(|v| v.to_string()));
+
+                    let mut expected = FixedVecDeque::<[String; 4]>::new();
+                    expected.head = head;
+                    expected.extend((0..len).map(|v| v.to_string()));
+
+                    for v in 0..n {
+                        *expected.push_back() = format!("new {}", v);
+                    }
 
-                let (sa_front, sa_mid) = sa.split_at(front);
-                let (ob_mid, ob_back) = ob.split_at(mid);
-                debug_assert_eq!(sa_front.len(), oa.len());
-                debug_assert_eq!(sa_mid.len(), ob_mid.len());
-                debug_assert_eq!(sb.len(), ob_back.len());
-                sa_front == oa && sa_mid == ob_mid && sb == ob_back
+                    let (a, b) = buf.push_back_n(n);
+                    let added = a.len() + b.len();
+                    assert_eq!(added, cmp::min(n, 4));
+
+                    for (i, v) in a.iter_mut().chain(b).enumerate() {
+                        *v = format!("new {}", n - added + i);
+                    }
+
+                    assert_eq!(buf, expected);
+                    assert_eq!(buf.head_index(), expected.head_index());
+                }
             }
         }
+
+        let mut empty = FixedVecDeque::<[u32; 0]>::new();
+        assert_eq!(empty.push_back_n(0), (&mut [][..], &mut [][..]));
     }
-}
 
-macro_rules! impl_slice_eq {
-    ($self:ident, $other:ident) => {
-        if $self.len() != $other.len() {
-            return false;
-        } else {
-            let (sa, sb) = $self.as_slices();
-            let (oa, ob) = $other[..].split_at(sa.len());
-            sa == oa && sb == ob
+    #[test]
+    fn test_push_back_n_overflow() {
+        let mut buf = FixedVecDeque::<[Rc<u32>; 4]>::new();
+        let values = (0..6).map(Rc::new).collect::<Vec<_>>();
+
+        let (a, b) = buf.push_back_n(6);
+        assert_eq!(a.len() + b.len(), 4);
+
+        for (slot, v) in a.iter_mut().chain(b).zip(&values[2..]) {
+            *slot = v.clone();
         }
-    };
-}
 
-impl<A, B> PartialEq<[B]> for FixedVecDeque<A>
-where
-    A: Array,
-    A::Item: PartialEq<B>,
-{
-    fn eq(&self, other: &[B]) -> bool {
-        impl_slice_eq!(self, other)
+        assert_eq!(buf.iter().map(|v| **v).collect::<Vec<_>>(), [2, 3, 4, 5]);
+        assert!(values[2..].iter().all(|v| Rc::strong_count(v) == 2));
+        drop(buf);
+        assert!(values.iter().all(|v| Rc::strong_count(v) == 1));
     }
-}
 
-impl<const N: usize, A, B> PartialEq<[B; N]> for FixedVecDeque<A>
-where
-    A: Array,
-    A::Item: PartialEq<B>,
-{
-    fn eq(&self, other: &[B; N]) -> bool {
-        impl_slice_eq!(self, other)
+    #[test]
+    #[should_panic(expected = "Cannot add to a zero-sized deque")]
+    fn test_push_back_n_zero_sized() {
+        let mut buf = FixedVecDeque::<[u32; 0]>::new();
+        buf.push_back_n(1);
     }
-}
 
-impl<A> PartialOrd for FixedVecDeque<A>
-where
-    A: Array,
-    A::Item: PartialOrd,
-{
-    fn partial_cmp(&self, other: &FixedVecDeque<A>) -> Option<cmp::Ordering> {
-        self.iter().partial_cmp(other.iter())
+    #[test]
+    fn test_eq_slices_of_different_lengths() {
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.head = 4;
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf.as_slices(), (&[1][..], &[2, 3][..]));
+
+        let mut shorter = [1, 2];
+        let mut longer = [1, 2, 3, 4, 5];
+
+        assert_ne!(buf, shorter[..]);
+        assert_ne!(buf, longer[..]);
+        assert_ne!(buf, &shorter[..]);
+        assert_ne!(buf, &longer[..]);
+        assert_ne!(buf, &mut shorter[..]);
+        assert_ne!(buf, &mut longer[..]);
+        assert_ne!(buf, shorter);
+        assert_ne!(buf, longer);
+        assert_ne!(buf, &shorter);
+        assert_ne!(buf, &longer);
+        assert_ne!(buf, &mut shorter);
+        assert_ne!(buf, &mut longer);
+        assert_ne!(buf, shorter.to_vec());
+        assert_ne!(buf, longer.to_vec());
+
+        assert_eq!(buf, [1, 2, 3]);
+        assert_eq!(buf, longer[..3]);
     }
-}
 
-impl<A> Ord for FixedVecDeque<A>
-where
-    A: Array,
-    A::Item: Ord,
-{
-    #[inline]
-    fn cmp(&self, other: &FixedVecDeque<A>) -> cmp::Ordering {
-        self.iter().cmp(other.iter())
+    #[test]
+    fn test_pairwise() {
+        let mut buf = FixedVecDeque::<[i32; 5]>::new();
+        buf.head = 3;
+        buf.extend([10, 12, 11, 15, 20]);
+        assert_eq!(buf.as_slices(), (&[10, 12][..], &[11, 15, 20][..]));
+
+        let deltas = buf.pairwise().map(|(a, b)| b - a).collect::<Vec<_>>();
+        assert_eq!(deltas, [2, -1, 4, 5]);
+
+        let deltas = buf
+            .pairwise()
+            .zip(buf.windows(2))
+            .all(|((a, b), mut w)| w.next() == Some(a) && w.next() == Some(b));
+        assert!(deltas);
+
+        buf.truncate(1);
+        assert_eq!(buf.pairwise().count(), 0);
+        buf.clear();
+        assert_eq!(buf.pairwise().count(), 0);
+    }
+
+    #[test]
+    fn test_back_or_push() {
+        let mut buf = FixedVecDeque::<[u32; 3]>::new();
+        *buf.back_or_push() = 1;
+        assert_eq!(buf, [1]);
+        *buf.back_or_push() += 10;
+        assert_eq!(buf, [11]);
+
+        buf.head = 2;
+        buf.clear();
+        buf.extend([1, 2, 3]);
+        *buf.back_or_push() *= 2;
+        assert_eq!(buf, [1, 2, 6]);
+        assert_eq!(buf.len(), 3);
+    }
+
+    #[test]
+    fn test_iter_aged() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        assert_eq!(buf.iter_aged().count(), 0);
+
+        buf.head = 3;
+        for v in 1..=6 {
+            *buf.push_back() = v;
+            assert_eq!(buf.iter_aged().last(), Some((0, &v)));
+        }
+
+        let aged = buf.iter_aged().collect::<Vec<_>>();
+        assert_eq!(aged, vec![(3, &3), (2, &4), (1, &5), (0, &6)]);
     }
-}
 
-/// Returns the two slices that cover the `FixedVecDeque`'s valid range
-trait RingSlices: Sized {
-    fn slice(self, from: usize, to: usize) -> Self;
-    fn split_at(self, i: usize) -> (Self, Self);
+    #[test]
+    fn test_truncate_drop() {
+        let value = Rc::new(());
 
-    fn ring_slices(buf: Self, head: usize, tail: usize) -> (Self, Self) {
-        let contiguous = tail <= head;
-        if contiguous {
-            let (empty, buf) = buf.split_at(0);
-            (buf.slice(tail, head), empty)
-        } else {
-            let (mid, right) = buf.split_at(tail);
-            let (left, _) = mid.split_at(head);
-            (right, left)
+        let mut buf = FixedVecDeque::<[Option<Rc<()>>; 4]>::new();
+        buf.head = 3;
+
+        for _ in 0..4 {
+            *buf.push_back() = Some(value.clone());
         }
+
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        buf.truncate_drop(5);
+        assert_eq!(buf.len(), 4);
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        buf.truncate_drop(1);
+        assert_eq!(buf.len(), 1);
+        assert_eq!(Rc::strong_count(&value), 2);
+        assert!(buf.front().unwrap().is_some());
+
+        buf.truncate_drop(0);
+        assert!(buf.is_empty());
+        assert_eq!(Rc::strong_count(&value), 1);
     }
-}
 
-impl<T> RingSlices for &[T] {
-    fn slice(self, from: usize, to: usize) -> Self {
-        &self[from..to]
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_index_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.head = 2;
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf[2], 3);
+        let _ = buf[3];
     }
 
-    fn split_at(self, i: usize) -> (Self, Self) {
-        (*self).split_at(i)
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 7")]
+    fn test_index_mut_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf[7] = 1;
     }
-}
 
-impl<T> RingSlices for &mut [T] {
-    fn slice(self, from: usize, to: usize) -> Self {
-        &mut self[from..to]
+    #[test]
+    fn test_iovecs() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        assert_eq!(buf.iovecs(), ([&[][..], &[][..]], 0));
+
+        buf.head = 1;
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf.iovecs(), ([&[1, 2, 3][..], &[][..]], 1));
+
+        buf.clear();
+        buf.head = 3;
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf.iovecs(), ([&[1][..], &[2, 3][..]], 2));
+
+        buf.extend([4]);
+        assert!(buf.is_full());
+        assert_eq!(buf.iovecs(), ([&[1][..], &[2, 3, 4][..]], 2));
     }
 
-    fn split_at(self, i: usize) -> (Self, Self) {
-        (*self).split_at_mut(i)
+    #[test]
+    fn test_iter_mut_indexed() {
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.head = 3;
+        buf.extend([1, 2, 3, 4, 5]);
+
+        for (i, v) in buf.iter_mut_indexed() {
+            if i % 2 == 0 {
+                *v *= 100;
+            }
+        }
+
+        assert_eq!(buf, [100, 2, 300, 4, 500]);
+
+        let last = buf.iter_mut_indexed().next_back().map(|(i, v)| (i, *v));
+        assert_eq!(last, Some((4, 500)));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::{Array, FixedVecDeque};
-    use std::mem;
+    #[test]
+    fn test_swap_contents() {
+        let mut a = FixedVecDeque::<[String; 3]>::new();
+        a.head = 2;
+        a.extend(["a", "b"].map(String::from));
 
-    /// Construct a new and verify that its size is the sum of all it's elements.
-    fn test_new<T>() -> FixedVecDeque<T>
-    where
-        T: Array,
-        T::Item: Default,
-    {
-        let fixed = FixedVecDeque::<T>::new();
+        let mut b = FixedVecDeque::<[String; 3]>::new();
+        b.extend(["c", "d", "e", "f"].map(String::from));
 
+        a.swap_contents(&mut b);
+        assert_eq!(a, ["d", "e", "f"]);
+        assert_eq!(b, ["a", "b"]);
         assert_eq!(
-            mem::size_of::<T::Item>() * 4 + mem::size_of::<FixedVecDeque<[Zero; 1]>>(),
-            mem::size_of::<FixedVecDeque<[T::Item; 4]>>()
+            b.as_slices(),
+            (&["a".to_string()][..], &["b".to_string()][..])
         );
 
-        #[derive(Debug, Default, PartialEq, Eq)]
-        struct Zero {}
+        *a.push_back() = String::from("g");
+        b.clear();
+        assert_eq!(a, ["e", "f", "g"]);
+        assert!(b.is_empty());
+    }
 
-        fixed
+    #[test]
+    fn test_check_invariants() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        assert!(buf.check_invariants());
+
+        buf.head = 2;
+        buf.extend([1, 2, 3, 4, 5]);
+        assert!(buf.check_invariants());
+
+        buf.head = 4;
+        assert!(!buf.check_invariants());
+
+        buf.head = 1;
+        buf.len = 5;
+        assert!(!buf.check_invariants());
+
+        buf.len = 4;
+        assert!(buf.check_invariants());
+
+        let mut buf = FixedVecDeque::<[u32; 0]>::new();
+        assert!(buf.check_invariants());
+        buf.len = 1;
+        assert!(!buf.check_invariants());
     }
 
     #[test]
-    fn test_push_back() {
-        let mut fixed = test_new::<[Foo; 4]>();
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invariants violated: head is 7, len is 0 and capacity is 4")]
+    fn test_debug_check_invariants() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.head = 7;
+        buf.debug_check_invariants();
+    }
 
-        #[derive(Debug, Default, PartialEq, Eq)]
-        struct Foo {
-            data: u64,
+    #[test]
+    fn test_from_slices() {
+        let mut buf = FixedVecDeque::<[String; 4]>::new();
+        buf.head = 3;
+        buf.extend(["a", "b", "c"].map(String::from));
+        assert!(!buf.is_contiguous());
+
+        let (a, b) = buf.as_slices();
+        let copy = FixedVecDeque::<[String; 4]>::from_slices(a, b).unwrap();
+        assert_eq!(copy, buf);
+        assert!(copy.is_contiguous());
+        assert!(copy.check_invariants());
+
+        let full = FixedVecDeque::<[u32; 3]>::from_slices(&[1], &[2, 3]).unwrap();
+        assert_eq!(full, [1, 2, 3]);
+        assert_eq!(full.head_index(), 0);
+        assert!(full.is_full());
+
+        let empty = FixedVecDeque::<[u32; 3]>::from_slices(&[], &[]).unwrap();
+        assert!(empty.is_empty());
+
+        let err = FixedVecDeque::<[u32; 0]>::from_slices(&[], &[1]).unwrap_err();
+        assert_eq!(err.capacity(), 0);
+        assert!(FixedVecDeque::<[u32; 0]>::from_slices(&[], &[]).is_ok());
+    }
+
+    #[test]
+    fn test_align_front_to() {
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.head = 4;
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf.tail_index(), 4);
+
+        for physical in [0, 3, 1, 4, 2, 2] {
+            buf.align_front_to(physical);
+            assert_eq!(buf.tail_index(), physical);
+            assert_eq!(buf.head_index(), (physical + 3) % 5);
+            assert_eq!(buf, [1, 2, 3]);
+            assert!(buf.check_invariants());
         }
 
-        fixed.push_back().data = 1;
-        fixed.push_back().data = 2;
+        buf.extend([4, 5]);
+        assert!(buf.is_full());
 
-        assert_eq!(Some(&mut Foo { data: 1 }), fixed.pop_front());
-        assert_eq!(Some(&mut Foo { data: 2 }), fixed.pop_front());
-        assert_eq!(None, fixed.pop_front());
+        for physical in [0, 4, 1] {
+            buf.align_front_to(physical);
+            assert_eq!(buf.tail_index(), physical);
+            assert_eq!(buf.head_index(), physical);
+            assert_eq!(buf, [1, 2, 3, 4, 5]);
+        }
+
+        buf.clear();
+        buf.align_front_to(3);
+        assert_eq!(buf.head_index(), 3);
+        *buf.push_back() = 9;
+        assert_eq!(buf.tail_index(), 3);
     }
 
-    // make sure that we correctly ported the various functions, since they depended on sizes being
-    // aligned to a power of two.
     #[test]
-    #[allow(clippy::reversed_empty_ranges)]
-    fn test_unaligned_sizes() {
-        macro_rules! test_size {
-            ($size:expr) => {
-                let mut buf = FixedVecDeque::<[u32; $size]>::new();
-
-                assert_eq!(buf.back(), None);
-                assert_eq!(buf.front(), None);
-                assert_eq!(buf.get(0), None);
-                assert_eq!(buf.get_mut(0), None);
+    #[should_panic(expected = "physical index 4 is out of bounds for capacity 4")]
+    fn test_align_front_to_out_of_bounds() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+        buf.align_front_to(4);
+    }
 
-                for i in 1..($size + 1) {
-                    *buf.push_back() = i;
+    #[test]
+    fn test_snapshot() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
 
-                    assert_eq!(buf.front(), Some(&1));
-                    assert_eq!(buf.back(), Some(&i));
-                    assert_eq!(buf.get(0), Some(&1));
-                    assert_eq!(buf.get(buf.len() - 1), Some(&i));
-                    assert_eq!(buf[0], 1);
-                    assert_eq!(buf[buf.len() - 1], i);
-                }
+        let mut buf = FixedVecDeque::<[u64; 8]>::new();
+        buf.head = 6;
+        buf.extend(1..=5);
+        assert!(!buf.is_contiguous());
 
-                let mut buf = FixedVecDeque::<[u32; $size]>::new();
+        let snapshot = buf.snapshot();
+        assert_send_sync(&snapshot);
+        buf.clear();
 
-                assert_eq!(buf.back(), None);
-                assert_eq!(buf.front(), None);
-                assert_eq!(buf.get(0), None);
-                assert_eq!(buf.get_mut(0), None);
+        let handle = ::std::thread::spawn(move || snapshot.iter().sum::<u64>());
+        assert_eq!(handle.join().unwrap(), 15);
 
-                for i in 1..($size + 1) {
-                    *buf.push_front() = i;
+        buf.extend([7, 8]);
+        let snapshot = buf.snapshot();
+        assert_eq!(format!("{:?}", snapshot), "[7, 8]");
+        assert_eq!(snapshot.into_iter().collect::<Vec<_>>(), vec![7, 8]);
+    }
 
-                    assert_eq!(buf.back(), Some(&1));
-                    assert_eq!(buf.front(), Some(&i));
-                    assert_eq!(buf.get(buf.len() - 1), Some(&1));
-                    assert_eq!(buf.get(0), Some(&i));
-                    assert_eq!(buf[buf.len() - 1], 1);
-                    assert_eq!(buf[0], i);
-                }
-            };
-        }
+    #[test]
+    fn test_rfind() {
+        let mut buf = FixedVecDeque::<[(u32, u32); 5]>::new();
+        buf.head = 3;
+        buf.extend([(0, 1), (1, 2), (2, 1), (3, 2), (4, 1)]);
+        assert!(!buf.is_contiguous());
+
+        assert_eq!(buf.rfind(|v| v.1 == 1), Some(&(4, 1)));
+        assert_eq!(buf.rfind(|v| v.1 == 2), Some(&(3, 2)));
+        assert_eq!(buf.rfind(|v| v.0 < 2 && v.1 == 1), Some(&(0, 1)));
+        assert_eq!(buf.rfind(|v| v.1 == 3), None);
+
+        let mut visited = Vec::new();
+        buf.rfind(|v| {
+            visited.push(v.0);
+            v.0 == 2
+        });
+        assert_eq!(visited, [4, 3, 2]);
+    }
 
-        test_size!(0);
-        test_size!(1);
-        test_size!(2);
-        test_size!(3);
-        test_size!(4);
-        test_size!(5);
-        test_size!(6);
-        test_size!(7);
-        test_size!(8);
-        test_size!(9);
-        test_size!(10);
-        test_size!(11);
-        test_size!(12);
-        test_size!(13);
-        test_size!(14);
-        test_size!(15);
-        test_size!(16);
-        test_size!(20);
-        test_size!(24);
-        test_size!(32);
-        test_size!(36);
+    #[test]
+    fn test_fill_to_capacity() {
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.head = 3;
+        buf.extend([1, 2, 3, 4, 5]);
+        buf.truncate(2);
+
+        buf.fill_to_capacity();
+        assert!(buf.is_full());
+        assert_eq!(buf, [1, 2, 0, 0, 0]);
+
+        buf.fill_to_capacity();
+        assert_eq!(buf, [1, 2, 0, 0, 0]);
+
+        let mut buf = FixedVecDeque::<[u32; 0]>::new();
+        buf.fill_to_capacity();
+        assert!(buf.is_empty());
     }
 
     #[test]
-    fn test_drop() {
-        let mut a = 0;
-        let mut b = 0;
-        let mut c = 0;
+    fn test_iter_mut_as_iter() {
+        let mut buf = FixedVecDeque::<[u32; 5]>::new();
+        buf.head = 3;
+        buf.extend([1, 2, 3, 4, 5]);
 
         {
-            let mut fixed = FixedVecDeque::<[Foo; 2]>::new();
-            fixed.push_back().value = Some(&mut a);
-            fixed.push_back().value = Some(&mut b);
-            fixed.push_back().value = Some(&mut c);
+            let mut it = buf.iter_mut();
+            let first = it.next().unwrap();
+            let last = it.next_back().unwrap();
+            assert_eq!(it.as_iter().collect::<Vec<_>>(), [&2, &3, &4]);
+
+            *first = 10;
+            *last = 50;
+
+            for v in it.by_ref().take(1) {
+                *v = 20;
+            }
+
+            assert_eq!(it.as_iter().len(), 2);
+            assert_eq!(it.as_iter().collect::<Vec<_>>(), [&3, &4]);
         }
 
-        // NB: zero because it will have been overwritten due to the circular nature of the buffer.
-        assert_eq!(a, 0);
-        assert_eq!(b, 1);
-        assert_eq!(c, 1);
+        assert_eq!(buf.iter().collect::<Vec<_>>(), [&10, &20, &3, &4, &50]);
+    }
 
-        #[derive(Default)]
-        struct Foo<'a> {
-            value: Option<&'a mut u32>,
+    #[test]
+    #[cfg(feature = "overwrite-count")]
+    fn test_overwrite_count() {
+        let mut buf = FixedVecDeque::<[u32; 4]>::new();
+
+        for v in 0..7 {
+            *buf.push_back() = v;
         }
 
-        impl Drop for Foo<'_> {
-            fn drop(&mut self) {
-                if let Some(v) = self.value.take() {
-                    *v += 1;
-                }
-            }
+        assert_eq!(buf, [3, 4, 5, 6]);
+        assert_eq!(buf.overwrite_count(), 3);
+
+        *buf.push_front() = 2;
+        assert_eq!(buf.overwrite_count(), 4);
+
+        buf.pop_back();
+        *buf.push_front() = 1;
+        assert_eq!(buf.overwrite_count(), 4);
+
+        buf.push_back_n(6);
+        assert_eq!(buf.overwrite_count(), 10);
+        assert_eq!(buf.clone().overwrite_count(), 10);
+
+        buf.clear();
+        assert_eq!(buf.overwrite_count(), 0);
+        buf.push_back_n(3);
+        assert_eq!(buf.overwrite_count(), 0);
+
+        let mut buf = FixedVecDeque::<[u32; 2]>::new();
+
+        for v in [1, 2, 3, 0] {
+            buf.insert_sorted(v);
         }
+
+        assert_eq!(buf, [2, 3]);
+        assert_eq!(buf.overwrite_count(), 1);
+
+        let mut other = FixedVecDeque::<[u32; 2]>::new();
+        other.extend([0, 1]);
+        buf.merge_sorted(&other);
+        assert_eq!(buf, [0, 1]);
+        assert_eq!(buf.overwrite_count(), 1);
     }
 
     #[test]
-    fn test_extend() {
-        let mut deq = FixedVecDeque::<[u32; 4]>::new();
-        deq.extend([1, 2, 3, 4, 5, 6, 7, 8]);
+    fn test_iter_for_each() {
+        for head in 0..5 {
+            for len in 0..=7 {
+                let mut buf = FixedVecDeque::<[u32; 5]>::new();
+                buf.head = head;
+                buf.extend(0..len);
+
+                let expected = buf.iter().copied().collect::<Vec<_>>();
+
+                let mut out = Vec::new();
+                buf.iter().for_each(|v| out.push(*v));
+                assert_eq!(out, expected);
+
+                let mut it = buf.iter();
+                it.next_back();
+                let mut out = Vec::new();
+                it.for_each(|v| out.push(*v));
+                assert_eq!(out, &expected[..expected.len().saturating_sub(1)]);
+            }
+        }
 
-        assert!(!deq.is_empty());
-        assert!(deq.is_full());
-        assert_eq!(deq.iter().collect::<Vec<_>>(), vec![&5, &6, &7, &8]);
+        let buf = FixedVecDeque::<[u32; 0]>::new();
+        let mut calls = 0;
+        buf.iter().for_each(|_| calls += 1);
+        assert_eq!(calls, 0);
     }
 
     #[test]
-    fn test_collect() {
-        let deq: FixedVecDeque<[u32; 4]> = vec![1, 2, 3, 4, 5, 6, 7, 8].into_iter().collect();
+    fn test_wrap_near_usize_max() {
+        type Large = [u8; 0x100000];
+        type Huge = [(); usize::MAX];
+
+        let last = Large::size() - 1;
+        assert_eq!(Large::wrap_add(last, 1), 0);
+        assert_eq!(Large::wrap_add(last, usize::MAX), last - 1);
+        assert_eq!(Large::wrap_add(usize::MAX, usize::MAX), last - 1);
+        assert_eq!(Large::wrap_sub(0, 1), last);
+        assert_eq!(Large::wrap_sub(0, Large::size()), 0);
+        assert_eq!(Large::wrap_sub(1, usize::MAX), 2);
+        assert_eq!(Large::wrap_sub(0, Large::size() + 1), last);
+
+        assert_eq!(Huge::wrap_add(usize::MAX - 1, 1), 0);
+        assert_eq!(
+            Huge::wrap_add(usize::MAX - 1, usize::MAX - 1),
+            usize::MAX - 2
+        );
+        assert_eq!(Huge::wrap_sub(0, usize::MAX - 1), 1);
+        assert_eq!(Huge::wrap_sub(0, usize::MAX), 0);
+
+        let mut buf = FixedVecDeque::<super::BoxedArray<u8, 0x100000>>::new();
+        buf.head = last - 1;
+        buf.extend([1, 2, 3]);
+        assert_eq!(buf.head_index(), 1);
+        assert_eq!(buf.tail_index(), last - 1);
+        assert_eq!(buf, [1, 2, 3]);
+        assert!(buf.check_invariants());
+    }
 
-        assert!(!deq.is_empty());
-        assert!(deq.is_full());
-        assert_eq!(deq.iter().collect::<Vec<_>>(), vec![&5, &6, &7, &8]);
+    #[test]
+    fn test_from_raw_parts() {
+        let mut buf = FixedVecDeque::<[String; 4]>::new();
+        buf.head = 3;
+        buf.extend(["a", "b", "c"].map(String::from));
+
+        let data = ["b", "c", "", "a"].map(String::from);
+        let copy = unsafe { FixedVecDeque::from_raw_parts(data, buf.head_index(), buf.len()) };
+        assert!(copy.check_invariants());
+        assert_eq!(copy.tail_index(), buf.tail_index());
+        assert_eq!(copy.as_slices(), buf.as_slices());
+        assert_eq!(copy, ["a", "b", "c"]);
+
+        let (a, b) = copy.as_slices();
+        let copy = FixedVecDeque::<[String; 4]>::from_slices(a, b).unwrap();
+        let data = ["a", "b", "c", ""].map(String::from);
+        let raw = unsafe { FixedVecDeque::from_raw_parts(data, copy.head_index(), copy.len()) };
+        assert_eq!(raw.as_slices(), copy.as_slices());
+
+        let empty = unsafe { FixedVecDeque::from_raw_parts([0u32; 0], 0, 0) };
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_clone() {
-        let a: FixedVecDeque<[u32; 4]> = vec![1, 2, 3, 4].into_iter().collect();
-        let b = a.clone();
-        assert_eq!(a, b);
+    fn test_concat_into() {
+        let mut a = FixedVecDeque::<[u32; 4]>::new();
+        a.head = 3;
+        a.extend([1, 2, 3]);
+        let mut b = FixedVecDeque::<[u32; 4]>::new();
+        b.head = 2;
+        b.extend([4, 5, 6, 7]);
+
+        let mut dst = FixedVecDeque::<[u32; 8]>::new();
+        dst.extend([10, 11]);
+        a.concat_into(&b, &mut dst);
+        assert_eq!(dst, [1, 2, 3, 4, 5, 6, 7]);
+
+        let mut dst = FixedVecDeque::<[u32; 7]>::new();
+        a.concat_into(&b, &mut dst);
+        assert!(dst.is_full());
+        assert_eq!(dst, [1, 2, 3, 4, 5, 6, 7]);
+
+        let mut dst = FixedVecDeque::<[u32; 5]>::new();
+        a.concat_into(&b, &mut dst);
+        assert!(dst.is_full());
+        assert_eq!(dst, [1, 2, 3, 4, 5]);
+
+        let mut dst = FixedVecDeque::<[u32; 2]>::new();
+        a.concat_into(&b, &mut dst);
+        assert_eq!(dst, [1, 2]);
+
+        let mut dst = FixedVecDeque::<[u32; 0]>::new();
+        a.concat_into(&b, &mut dst);
+        assert!(dst.is_empty());
+
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(b, [4, 5, 6, 7]);
     }
 
     #[test]
@@ -1942,6 +9090,110 @@ mod benches {
         })
     }
 
+    #[bench]
+    fn bench_rotate_left_wrapped(b: &mut test::Bencher) {
+        let mut deq = FixedVecDeque::<[u32; 0x10000]>::new();
+        deq.extend(0..0x18000);
+        deq.pop_back();
+        assert!(!deq.is_contiguous());
+
+        b.iter(|| {
+            deq.rotate_left(0x1234);
+            deq.rotate_right(0x5678);
+            // the rotations leave the buffer contiguous, so wrap it around again.
+            let front = *deq.front().unwrap();
+            *deq.push_back() = front;
+            deq.pop_front();
+            deq.rotate_right(0x100);
+        })
+    }
+
+    #[bench]
+    fn bench_iter_fold(b: &mut test::Bencher) {
+        let mut deq = FixedVecDeque::<[u64; 0x1000]>::new();
+        deq.extend(0..0x1800);
+
+        b.iter(|| {
+            test::black_box(&deq)
+                .iter()
+                .fold(0u64, |acc, v| acc.wrapping_add(*v))
+        })
+    }
+
+    #[bench]
+    fn bench_iter_next(b: &mut test::Bencher) {
+        let mut deq = FixedVecDeque::<[u64; 0x1000]>::new();
+        deq.extend(0..0x1800);
+
+        b.iter(|| {
+            let mut acc = 0u64;
+
+            // NB: for loops go through next rather than fold.
+            for v in test::black_box(&deq).iter() {
+                acc = acc.wrapping_add(*v);
+            }
+
+            acc
+        })
+    }
+
+    #[bench]
+    fn bench_clone_100(b: &mut test::Bencher) {
+        let source = (0..100).collect::<FixedVecDeque<[u64; 0x100]>>();
+
+        b.iter(|| {
+            let mut deq = FixedVecDeque::<[u64; 0x100]>::new();
+            test::black_box(&mut deq);
+            deq = source.clone();
+            deq
+        })
+    }
+
+    #[bench]
+    fn bench_clone_from_100(b: &mut test::Bencher) {
+        let source = (0..100).collect::<FixedVecDeque<[u64; 0x100]>>();
+
+        b.iter(|| {
+            let mut deq = FixedVecDeque::<[u64; 0x100]>::new();
+            test::black_box(&mut deq);
+            deq.clone_from(&source);
+            deq
+        })
+    }
+
+    #[bench]
+    fn bench_pop_front_copied_100(b: &mut test::Bencher) {
+        let mut deq = FixedVecDeque::<[u64; 0x100]>::new();
+
+        b.iter(|| {
+            deq.extend(0..100);
+            let mut sum = 0;
+
+            while let Some(v) = deq.pop_front_copied() {
+                sum += v;
+            }
+
+            sum
+        })
+    }
+
+    #[bench]
+    fn bench_pop_front_manual_copy_100(b: &mut test::Bencher) {
+        let mut deq = FixedVecDeque::<[u64; 0x100]>::new();
+
+        b.iter(|| {
+            deq.extend(0..100);
+            let mut sum = 0;
+
+            while let Some(v) = deq.pop_front() {
+                let v = *v;
+                sum += v;
+            }
+
+            sum
+        })
+    }
+
     pub struct BigStruct {
         fields: [u64; 64],
     }
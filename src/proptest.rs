@@ -0,0 +1,52 @@
+//! Support for generating arbitrary deques through [`proptest`].
+//!
+//! [`proptest`]: https://docs.rs/proptest
+
+use std::cmp;
+use std::fmt;
+
+use ::proptest::collection;
+use ::proptest::strategy::Strategy;
+
+use crate::{Array, FixedVecDeque};
+
+/// Construct a [`Strategy`] which generates a `FixedVecDeque` with elements generated by
+/// `element`.
+///
+/// Both the length and the position of the elements in the underlying buffer are varied, so that
+/// contiguous as well as wrapped layouts are covered.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_vec_deque::fixed_vec_deque_strategy;
+/// use proptest::prelude::*;
+/// use proptest::test_runner::TestRunner;
+///
+/// let mut runner = TestRunner::default();
+/// let strategy = fixed_vec_deque_strategy::<[u32; 8], _>(any::<u32>());
+///
+/// runner
+///     .run(&strategy, |deq| {
+///         prop_assert_eq!(deq.len(), deq.iter().count());
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+///
+/// [`Strategy`]: https://docs.rs/proptest/1/proptest/strategy/trait.Strategy.html
+pub fn fixed_vec_deque_strategy<T, S>(element: S) -> impl Strategy<Value = FixedVecDeque<T>>
+where
+    T: Array,
+    T::Item: Default + fmt::Debug,
+    S: Strategy<Value = T::Item>,
+{
+    let size = T::size();
+
+    (collection::vec(element, 0..=size), 0..cmp::max(size, 1)).prop_map(|(items, head)| {
+        let mut deq = FixedVecDeque::new();
+        deq.head = head;
+        deq.extend(items);
+        deq
+    })
+}
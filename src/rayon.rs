@@ -0,0 +1,36 @@
+//! Parallel iterator support through [`rayon`].
+//!
+//! [`rayon`]: https://docs.rs/rayon
+
+use ::rayon::iter::{Chain, IntoParallelIterator, ParallelIterator};
+use ::rayon::slice::{Iter, IterMut};
+
+use crate::{Array, FixedVecDeque};
+
+impl<'a, T: 'a> IntoParallelIterator for &'a FixedVecDeque<T>
+where
+    T: Array,
+    T::Item: Sync,
+{
+    type Item = &'a T::Item;
+    type Iter = Chain<Iter<'a, T::Item>, Iter<'a, T::Item>>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let (a, b) = self.as_slices();
+        a.into_par_iter().chain(b)
+    }
+}
+
+impl<'a, T: 'a> IntoParallelIterator for &'a mut FixedVecDeque<T>
+where
+    T: Array,
+    T::Item: Send,
+{
+    type Item = &'a mut T::Item;
+    type Iter = Chain<IterMut<'a, T::Item>, IterMut<'a, T::Item>>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        let (a, b) = self.as_mut_slices();
+        a.into_par_iter().chain(b)
+    }
+}
@@ -0,0 +1,42 @@
+//! Support for wiping deques through [`zeroize`].
+//!
+//! [`zeroize`]: https://docs.rs/zeroize
+
+use ::zeroize::Zeroize;
+
+use crate::{Array, FixedVecDeque};
+
+/// Zeroizing wipes every slot in the underlying buffer, including the ones which are not part of
+/// the queue, and then clears the `FixedVecDeque`.
+///
+/// To wipe the buffer when it's dropped, wrap it in [`Zeroizing`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_vec_deque::FixedVecDeque;
+/// use zeroize::{Zeroize, Zeroizing};
+///
+/// let mut buf = FixedVecDeque::<[u8; 8]>::new();
+/// buf.extend(*b"secret");
+/// buf.zeroize();
+/// assert!(buf.is_empty());
+///
+/// let mut buf = Zeroizing::new(FixedVecDeque::<[u8; 8]>::new());
+/// buf.extend(*b"secret");
+/// ```
+///
+/// [`Zeroizing`]: https://docs.rs/zeroize/1/zeroize/struct.Zeroizing.html
+impl<T> Zeroize for FixedVecDeque<T>
+where
+    T: Array,
+    T::Item: Zeroize,
+{
+    fn zeroize(&mut self) {
+        for elem in unsafe { self.buffer_as_mut_slice() } {
+            elem.zeroize();
+        }
+
+        self.clear();
+    }
+}